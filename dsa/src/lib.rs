@@ -56,6 +56,9 @@ pub use signature;
 
 use pkcs8::spki::ObjectIdentifier;
 
+#[cfg(feature = "hazmat")]
+pub mod primality;
+
 mod components;
 mod generate;
 mod signing_key;
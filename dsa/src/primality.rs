@@ -0,0 +1,20 @@
+//!
+//! Primality testing for validating DSA domain parameters
+//!
+
+use num_bigint::{prime::probably_prime, BigUint};
+
+/// Test whether `n` is prime using `rounds` iterations of the Miller-Rabin
+/// primality test followed by a Lucas test, i.e. a Baillie-PSW test with
+/// additional Miller-Rabin rounds.
+///
+/// Returns `false` if `n` is definitely composite, and `true` if `n` is prime
+/// with an error probability of at most `4^-rounds` (the result is exact for
+/// `n < 2^64`).
+///
+/// This is the same test used when generating DSA components, and can be used
+/// to validate the `p` and `q` values of imported DSA domain parameters at a
+/// confidence level chosen by the caller.
+pub fn is_probable_prime(n: &BigUint, rounds: usize) -> bool {
+    probably_prime(n, rounds)
+}
@@ -20,7 +20,7 @@ use pkcs8::{
 use signature::{
     hazmat::{PrehashSigner, RandomizedPrehashSigner},
     rand_core::CryptoRngCore,
-    DigestSigner, RandomizedDigestSigner, Signer,
+    DigestSigner, RandomizedDigestSigner, RandomizedSigner, Signer,
 };
 use zeroize::{Zeroize, Zeroizing};
 
@@ -28,6 +28,15 @@ use zeroize::{Zeroize, Zeroizing};
 ///
 /// The [`(try_)sign_digest_with_rng`](::signature::RandomizedDigestSigner) API uses regular non-deterministic signatures,
 /// while the [`(try_)sign_digest`](::signature::DigestSigner) API uses deterministic signatures as described in RFC 6979
+///
+/// # Choosing how `k` is derived
+///
+/// - Deterministic (the [`Signer`], [`DigestSigner`], and [`PrehashSigner`] impls): `k` is derived from the
+///   private key and the message digest as described in RFC 6979. The same message always produces the same
+///   signature, and the security of the key does not depend on the quality of an RNG at signing time.
+/// - Randomized (the [`RandomizedSigner`], [`RandomizedDigestSigner`], and [`RandomizedPrehashSigner`] impls):
+///   `k` is drawn from the provided RNG as described in FIPS 186-4 Appendix B.2.1. Signatures over the same
+///   message differ, which can help against fault attacks, but a weak or repeating RNG leaks the private key.
 #[derive(Clone, PartialEq)]
 #[must_use]
 pub struct SigningKey {
@@ -119,6 +128,17 @@ impl Signer<Signature> for SigningKey {
     }
 }
 
+impl RandomizedSigner<Signature> for SigningKey {
+    fn try_sign_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg: &[u8],
+    ) -> Result<Signature, signature::Error> {
+        let digest = sha2::Sha256::new_with_prefix(msg);
+        self.try_sign_digest_with_rng(rng, digest)
+    }
+}
+
 impl PrehashSigner<Signature> for SigningKey {
     /// Warning: This uses `sha2::Sha256` as the hash function for the digest. If you need to use a different one, use [`SigningKey::sign_prehashed_rfc6979`].
     fn sign_prehash(&self, prehash: &[u8]) -> Result<Signature, signature::Error> {
@@ -0,0 +1,42 @@
+#![cfg(feature = "hazmat")]
+
+use dsa::{primality::is_probable_prime, BigUint};
+
+const ROUNDS: usize = 32;
+
+#[test]
+fn small_values() {
+    for n in [0_u32, 1, 4, 9, 15, 91] {
+        assert!(!is_probable_prime(&BigUint::from(n), ROUNDS), "{n}");
+    }
+
+    for n in [2_u32, 3, 5, 7, 11, 97, 65537] {
+        assert!(is_probable_prime(&BigUint::from(n), ROUNDS), "{n}");
+    }
+}
+
+#[test]
+fn carmichael_numbers() {
+    for n in [561_u32, 1105, 1729, 2465, 2821, 6601, 8911] {
+        assert!(!is_probable_prime(&BigUint::from(n), ROUNDS), "{n}");
+    }
+}
+
+#[test]
+fn strong_pseudoprimes() {
+    // Strong pseudoprimes to base 2, and to all prime bases up to 23
+    for n in [2047_u64, 3215031751, 3825123056546413051] {
+        assert!(!is_probable_prime(&BigUint::from(n), ROUNDS), "{n}");
+    }
+}
+
+#[test]
+fn large_values() {
+    // 2^127 - 1 and 2^61 - 1 are Mersenne primes
+    let m127 = (BigUint::from(1_u8) << 127) - 1_u8;
+    let m61 = (BigUint::from(1_u8) << 61) - 1_u8;
+
+    assert!(is_probable_prime(&m127, ROUNDS));
+    assert!(is_probable_prime(&m61, ROUNDS));
+    assert!(!is_probable_prime(&(&m127 * &m61), ROUNDS));
+}
@@ -8,7 +8,7 @@ use num_bigint::BigUint;
 use num_traits::Zero;
 use pkcs8::{DecodePrivateKey, EncodePrivateKey, LineEnding};
 use sha1::Sha1;
-use signature::{DigestVerifier, RandomizedDigestSigner};
+use signature::{DigestVerifier, RandomizedDigestSigner, RandomizedSigner, Signer, Verifier};
 
 const OPENSSL_PEM_PRIVATE_KEY: &str = include_str!("pems/private.pem");
 
@@ -54,6 +54,26 @@ fn sign_and_verify() {
         .is_ok());
 }
 
+#[test]
+fn randomized_and_deterministic_signatures() {
+    const DATA: &[u8] = b"SIGN AND VERIFY THOSE BYTES";
+
+    let signing_key = generate_keypair();
+    let verifying_key = signing_key.verifying_key();
+    let mut rng = rand::thread_rng();
+
+    let randomized1 = signing_key.sign_with_rng(&mut rng, DATA);
+    let randomized2 = signing_key.sign_with_rng(&mut rng, DATA);
+    assert_ne!(randomized1, randomized2);
+    assert!(verifying_key.verify(DATA, &randomized1).is_ok());
+    assert!(verifying_key.verify(DATA, &randomized2).is_ok());
+
+    let deterministic1 = signing_key.sign(DATA);
+    let deterministic2 = signing_key.sign(DATA);
+    assert_eq!(deterministic1, deterministic2);
+    assert!(verifying_key.verify(DATA, &deterministic1).is_ok());
+}
+
 #[test]
 fn verify_validity() {
     let signing_key = generate_keypair();
@@ -22,6 +22,9 @@ use {
     spki::{der::asn1::BitString, SignatureBitStringEncoding},
 };
 
+#[cfg(feature = "arithmetic")]
+use elliptic_curve::CurveArithmetic;
+
 #[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
 
@@ -138,6 +141,17 @@ where
         self.as_bytes().to_vec().into_boxed_slice()
     }
 
+    /// Convert this signature into the fixed-width [`crate::Signature`] type
+    /// without consuming it.
+    pub fn to_signature(&self) -> Result<crate::Signature<C>> {
+        let mut bytes = crate::SignatureBytes::<C>::default();
+        let r_begin = C::FieldBytesSize::USIZE.saturating_sub(self.r().len());
+        let s_begin = bytes.len().saturating_sub(self.s().len());
+        bytes[r_begin..C::FieldBytesSize::USIZE].copy_from_slice(self.r());
+        bytes[s_begin..].copy_from_slice(self.s());
+        crate::Signature::try_from(bytes.as_slice())
+    }
+
     /// Get the length of the signature in bytes
     pub fn len(&self) -> usize {
         self.s_range.end
@@ -154,6 +168,26 @@ where
     }
 }
 
+#[cfg(feature = "arithmetic")]
+impl<C> Signature<C>
+where
+    C: EcdsaCurve + CurveArithmetic,
+    MaxSize<C>: ArraySize,
+    <FieldBytesSize<C> as Add>::Output: Add<MaxOverhead> + ArraySize,
+{
+    /// Normalize signature into "low S" form as described in
+    /// [BIP 0062: Dealing with Malleability][1], re-encoding the result as
+    /// ASN.1 DER.
+    ///
+    /// Returns an error if the `r` or `s` components of this signature are
+    /// not valid non-zero scalars.
+    ///
+    /// [1]: https://github.com/bitcoin/bips/blob/master/bip-0062.mediawiki
+    pub fn normalize_s(&self) -> Result<Self> {
+        Ok(self.to_signature()?.normalize_s().to_der())
+    }
+}
+
 impl<C> AsRef<[u8]> for Signature<C>
 where
     C: EcdsaCurve,
@@ -277,12 +311,7 @@ where
     type Error = Error;
 
     fn try_from(sig: Signature<C>) -> Result<super::Signature<C>> {
-        let mut bytes = super::SignatureBytes::<C>::default();
-        let r_begin = C::FieldBytesSize::USIZE.saturating_sub(sig.r().len());
-        let s_begin = bytes.len().saturating_sub(sig.s().len());
-        bytes[r_begin..C::FieldBytesSize::USIZE].copy_from_slice(sig.r());
-        bytes[s_begin..].copy_from_slice(sig.s());
-        Self::try_from(bytes.as_slice())
+        sig.to_signature()
     }
 }
 
@@ -382,6 +411,72 @@ fn find_scalar_range(outer: &[u8], inner: &[u8]) -> Result<Range<usize>> {
     Ok(Range { start, end })
 }
 
+/// Decode the `r` and `s` components of a BER-encoded ECDSA signature,
+/// tolerating non-minimal (long form) length encodings and leading zero
+/// padding on the integers.
+///
+/// Returns the big endian `r` and `s` integers with leading zeros removed.
+pub(crate) fn decode_ber_lax(ber_bytes: &[u8]) -> Result<(&[u8], &[u8])> {
+    let (contents, trailing) = read_ber_tlv(ber_bytes, Tag::Sequence)?;
+    let (r, contents) = read_ber_tlv(contents, Tag::Integer)?;
+    let (s, contents) = read_ber_tlv(contents, Tag::Integer)?;
+
+    if !contents.is_empty() || !trailing.is_empty() {
+        return Err(Error::new());
+    }
+
+    Ok((strip_ber_uint(r)?, strip_ber_uint(s)?))
+}
+
+/// Read a BER tag-length-value with the given tag and a definite length,
+/// returning its value and the remaining input.
+fn read_ber_tlv(input: &[u8], tag: Tag) -> Result<(&[u8], &[u8])> {
+    let (&tag_byte, input) = input.split_first().ok_or_else(Error::new)?;
+
+    if tag_byte != u8::from(tag) {
+        return Err(Error::new());
+    }
+
+    let (&len_byte, input) = input.split_first().ok_or_else(Error::new)?;
+
+    let (len, input) = if len_byte < 0x80 {
+        (usize::from(len_byte), input)
+    } else {
+        // Long form: the low bits give the number of length octets. Zero
+        // length octets would be the (unsupported) indefinite form.
+        let num_octets = usize::from(len_byte & 0x7F);
+
+        if num_octets == 0 || num_octets > input.len() {
+            return Err(Error::new());
+        }
+
+        let (len_octets, input) = input.split_at(num_octets);
+        let len = len_octets.iter().try_fold(0usize, |len, &byte| {
+            len.checked_mul(0x100)?.checked_add(usize::from(byte))
+        });
+
+        (len.ok_or_else(Error::new)?, input)
+    };
+
+    if len > input.len() {
+        return Err(Error::new());
+    }
+
+    Ok(input.split_at(len))
+}
+
+/// Validate the contents of a BER `INTEGER` are non-empty and non-negative,
+/// returning them with any leading zeros removed.
+fn strip_ber_uint(bytes: &[u8]) -> Result<&[u8]> {
+    match bytes.first() {
+        Some(byte) if byte & 0x80 == 0 => {
+            let leading_zeros = bytes.iter().take_while(|&&b| b == 0).count();
+            Ok(&bytes[leading_zeros..])
+        }
+        _ => Err(Error::new()),
+    }
+}
+
 #[cfg(all(feature = "digest", feature = "hazmat"))]
 impl<C> signature::PrehashSignature for Signature<C>
 where
@@ -406,6 +501,10 @@ mod tests {
         0xcc, 0x5, 0x89, 0x3,
     ];
 
+    /// `n - 1` for `MockCurve`, i.e. the largest valid (and high) scalar.
+    const N_MINUS_ONE: [u8; 32] =
+        hex_literal::hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632550");
+
     #[test]
     fn test_fixed_to_asn1_signature_roundtrip() {
         let signature1 = Signature::try_from(EXAMPLE_SIGNATURE.as_ref()).unwrap();
@@ -417,6 +516,47 @@ mod tests {
         assert_eq!(signature1, signature2);
     }
 
+    #[test]
+    fn test_asn1_signature_borrowed_conversions() {
+        let signature1 = Signature::try_from(EXAMPLE_SIGNATURE.as_ref()).unwrap();
+        let asn1_signature = signature1.to_der();
+
+        let der_bytes: &[u8] = asn1_signature.as_ref();
+        assert_eq!(der_bytes, asn1_signature.as_bytes());
+        assert_eq!(Signature::from_der(der_bytes).unwrap(), signature1);
+        assert_eq!(asn1_signature.to_signature().unwrap(), signature1);
+    }
+
+    #[test]
+    fn test_asn1_normalize_s() {
+        use elliptic_curve::scalar::IsHigh;
+
+        // `s = n - 1` is high, and normalizes to `1`
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&EXAMPLE_SIGNATURE[..32]);
+        bytes[32..].copy_from_slice(&N_MINUS_ONE);
+        let high_s = Signature::try_from(bytes.as_ref()).unwrap();
+        assert!(bool::from(high_s.s().is_high()));
+
+        let normalized = high_s
+            .to_der()
+            .normalize_s()
+            .unwrap()
+            .to_signature()
+            .unwrap();
+        assert_eq!(normalized.to_bytes()[..32], high_s.to_bytes()[..32]);
+        assert!(!bool::from(normalized.s().is_high()));
+        assert_eq!(normalized, high_s.normalize_s());
+
+        // Already-low `s` values are unchanged
+        bytes[32..].fill(0x22);
+        let low_s = Signature::try_from(bytes.as_ref()).unwrap();
+        assert_eq!(
+            low_s.to_der().normalize_s().unwrap().as_bytes(),
+            low_s.to_der().as_bytes()
+        );
+    }
+
     #[test]
     fn test_asn1_too_short_signature() {
         assert!(Signature::from_der(&[]).is_err());
@@ -463,4 +603,44 @@ mod tests {
         ])
         .is_err());
     }
+
+    #[test]
+    fn test_asn1_short_components_are_left_padded() {
+        // `r` is 31 bytes and `s` is 30 bytes long
+        let mut der_bytes = [0u8; 67];
+        der_bytes[..4].copy_from_slice(&[
+            der::Tag::Sequence.into(),
+            65,
+            der::Tag::Integer.into(),
+            31,
+        ]);
+        der_bytes[4..35].fill(0x11);
+        der_bytes[35..37].copy_from_slice(&[der::Tag::Integer.into(), 30]);
+        der_bytes[37..].fill(0x22);
+
+        let signature = Signature::from_der(&der_bytes).unwrap();
+        let (r, s) = signature.split_bytes();
+
+        assert_eq!(r[..1], [0]);
+        assert_eq!(r[1..], [0x11; 31]);
+        assert_eq!(s[..2], [0, 0]);
+        assert_eq!(s[2..], [0x22; 30]);
+        assert_eq!(signature.to_der().as_bytes(), der_bytes);
+    }
+
+    #[test]
+    fn test_asn1_too_long_components() {
+        // `r` is 33 bytes long without a leading zero byte
+        let mut der_bytes = [0u8; 40];
+        der_bytes[..4].copy_from_slice(&[
+            der::Tag::Sequence.into(),
+            38,
+            der::Tag::Integer.into(),
+            33,
+        ]);
+        der_bytes[4..37].fill(0x11);
+        der_bytes[37..].copy_from_slice(&[der::Tag::Integer.into(), 1, 0x01]);
+
+        assert!(Signature::from_der(&der_bytes).is_err());
+    }
 }
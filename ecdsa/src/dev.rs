@@ -3,11 +3,16 @@
 // TODO(tarcieri): implement full set of tests from ECDSA2VS
 // <https://csrc.nist.gov/CSRC/media/Projects/Cryptographic-Algorithm-Validation-Program/documents/dss2/ecdsa2vs.pdf>
 
-use crate::EcdsaCurve;
+use crate::{hazmat::DigestPrimitive, EcdsaCurve};
 use elliptic_curve::dev::MockCurve;
 
 impl EcdsaCurve for MockCurve {
     const NORMALIZE_S: bool = false;
+    const CONSTANT_TIME_INVERT: bool = false;
+}
+
+impl DigestPrimitive for MockCurve {
+    type Digest = sha2::Sha256;
 }
 
 /// ECDSA test vector
@@ -23,7 +23,7 @@ use {
         ops::{Invert, LinearCombination, MulByGenerator, Reduce},
         point::AffineCoordinates,
         scalar::IsHigh,
-        CurveArithmetic, NonZeroScalar, ProjectivePoint, Scalar,
+        AffinePoint, CurveArithmetic, NonZeroScalar, ProjectivePoint, Scalar,
     },
 };
 
@@ -75,6 +75,15 @@ where
 /// larger than the size of the curve's scalar field into a serialized
 /// (unreduced) field element.
 ///
+/// Digests which are larger than the field are truncated to their leftmost
+/// bits as described in [SEC1] § 4.1.3 step 5 (e.g. a 64-byte prehash used
+/// with secp256k1 is truncated to its leftmost 256 bits).
+///
+/// Inputs which are shorter than half the field size, or longer than both
+/// twice the field size and the largest standard digest size (64 bytes,
+/// i.e. SHA-512), are rejected as they most likely indicate a caller passing
+/// something other than a message digest.
+///
 /// [RFC6979 § 2.3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-2.3.2
 /// [SEC1]: https://www.secg.org/sec1-v2.pdf
 pub fn bits2field<C: EcdsaCurve>(bits: &[u8]) -> Result<FieldBytes<C>> {
@@ -83,6 +92,12 @@ pub fn bits2field<C: EcdsaCurve>(bits: &[u8]) -> Result<FieldBytes<C>> {
         return Err(Error::new());
     }
 
+    // Maximum allowed bits size is twice the field size, or the size of a
+    // SHA-512 digest for curves with small fields
+    if bits.len() > cmp::max(C::FieldBytesSize::USIZE * 2, 64) {
+        return Err(Error::new());
+    }
+
     let mut field_bytes = FieldBytes::<C>::default();
 
     match bits.len().cmp(&C::FieldBytesSize::USIZE) {
@@ -92,7 +107,8 @@ pub fn bits2field<C: EcdsaCurve>(bits: &[u8]) -> Result<FieldBytes<C>> {
             field_bytes[(C::FieldBytesSize::USIZE - bits.len())..].copy_from_slice(bits);
         }
         cmp::Ordering::Greater => {
-            // If bits is larger than the field size, truncate
+            // If bits is larger than the field size, truncate to the leftmost
+            // bytes, e.g. a 64-byte prehash used with a 256-bit curve
             field_bytes.copy_from_slice(&bits[..C::FieldBytesSize::USIZE]);
         }
     }
@@ -110,6 +126,15 @@ pub fn bits2field<C: EcdsaCurve>(bits: &[u8]) -> Result<FieldBytes<C>> {
 /// - `z`: message digest to be signed. MUST BE OUTPUT OF A CRYPTOGRAPHICALLY
 ///        SECURE DIGEST ALGORITHM!!!
 ///
+/// # ⚠️ Warning: caller-supplied `k`
+///
+/// This is the raw ECDSA signing equation with a caller-provided nonce, which
+/// is mainly useful for reproducing known-answer tests (e.g. the `k` values
+/// given in the RFC6979 test vectors) and for debugging. Reusing `k` for two
+/// different messages, or using a `k` which is predictable or biased in any
+/// way, reveals the signing key. Use `sign_prehashed_rfc6979` to derive `k`
+/// deterministically instead.
+///
 /// # Low-S Normalization
 ///
 /// This function will apply low-S normalization if `<C as EcdsaCurve>::NORMALIZE_S` is true.
@@ -134,12 +159,8 @@ where
     // Compute scalar inversion of 𝑘
     let k_inv = k.invert();
 
-    // Compute 𝑹 = 𝑘×𝑮
-    let R = ProjectivePoint::<C>::mul_by_generator(k).to_affine();
-
-    // Lift x-coordinate of 𝑹 (element of base field) into a serialized big
-    // integer, then reduce it into an element of the scalar field
-    let r = Scalar::<C>::reduce_bytes(&R.x());
+    // Compute 𝑹 = 𝑘×𝑮 and 𝒓 = 𝑹.x mod 𝒏
+    let (R, r) = ephemeral_point(k);
     let x_is_reduced = r.to_repr() != R.x();
 
     // Compute 𝒔 as a signature over 𝒓 and 𝒛.
@@ -158,6 +179,27 @@ where
     Ok((signature, recovery_id))
 }
 
+/// Compute the ephemeral public point `R = k×G` for the ephemeral scalar `k`,
+/// along with the signature component `r`, i.e. the affine x-coordinate of
+/// `R` reduced modulo the order of the scalar field.
+///
+/// This is the first half of [`sign_prehashed`], and can be used to compute
+/// `r` ahead of time, e.g. when generating presignatures.
+///
+/// - `k`: ephemeral scalar value. MUST BE UNIFORMLY RANDOM!!!
+#[cfg(feature = "arithmetic")]
+pub fn ephemeral_point<C>(k: &NonZeroScalar<C>) -> (AffinePoint<C>, Scalar<C>)
+where
+    C: EcdsaCurve + CurveArithmetic,
+{
+    let big_r = ProjectivePoint::<C>::mul_by_generator(k).to_affine();
+
+    // Lift x-coordinate of 𝑹 (element of base field) into a serialized big
+    // integer, then reduce it into an element of the scalar field
+    let r = Scalar::<C>::reduce_bytes(&big_r.x());
+    (big_r, r)
+}
+
 /// Try to sign the given message digest deterministically using the method
 /// described in [RFC6979] for computing ECDSA ephemeral scalar `k`.
 ///
@@ -185,16 +227,47 @@ where
     //
     // h = bits2int(H(m)) mod q
     let z2 = <Scalar<C> as Reduce<C::Uint>>::reduce_bytes(z);
+    sign_scalar_rfc6979::<C, D>(d, &z2, ad)
+}
+
+/// Sign a message representative which has already been converted into a
+/// scalar, deterministically computing the ephemeral scalar `k` using the
+/// method described in [RFC6979].
+///
+/// This is a lower-level equivalent of [`sign_prehashed_rfc6979`] which skips
+/// the `bits2field` conversion and reduction of the message digest.
+///
+/// Accepts the following parameters:
+/// - `d`: signing key. MUST BE UNIFORMLY RANDOM!!!
+/// - `z`: message representative as a scalar, i.e. `bits2int(H(m)) mod n`.
+///        MUST BE DERIVED FROM THE OUTPUT OF A CRYPTOGRAPHICALLY SECURE DIGEST
+///        ALGORITHM!!! Signing attacker-chosen scalars can be used to forge
+///        signatures.
+/// - `ad`: optional additional data, e.g. added entropy from an RNG
+///
+/// [RFC6979]: https://datatracker.ietf.org/doc/html/rfc6979
+#[cfg(feature = "rfc6979")]
+pub fn sign_scalar_rfc6979<C, D>(
+    d: &NonZeroScalar<C>,
+    z: &Scalar<C>,
+    ad: &[u8],
+) -> Result<(Signature<C>, RecoveryId)>
+where
+    C: EcdsaCurve + CurveArithmetic,
+    D: Digest + BlockSizeUser + FixedOutput + FixedOutputReset,
+    SignatureSize<C>: ArraySize,
+{
+    let z = z.to_repr();
 
     let k = NonZeroScalar::<C>::from_repr(rfc6979::generate_k::<D, _>(
         &d.to_repr(),
         &C::ORDER.encode_field_bytes(),
-        &z2.to_repr(),
+        &z,
         ad,
     ))
     .unwrap();
 
-    sign_prehashed(d, &k, z)
+    sign_prehashed(d, &k, &z)
 }
 
 /// Verify the prehashed message against the provided ECDSA signature.
@@ -219,9 +292,78 @@ where
     C: EcdsaCurve + CurveArithmetic,
     SignatureSize<C>: ArraySize,
 {
-    let z = Scalar::<C>::reduce_bytes(z);
-    let (r, s) = sig.split_scalars();
+    let (_, s) = sig.try_split_scalars()?;
     let s_inv = *s.invert_vartime();
+    verify_prehashed_with_s_inv(q, z, sig, &s_inv)
+}
+
+/// Verify the prehashed message against an ECDSA signature given as the full
+/// ephemeral point `R` and the scalar `s`, e.g. as produced by a threshold
+/// signing protocol.
+///
+/// Unlike [`verify_prehashed`], which only knows `r = R.x mod n` and so
+/// accepts either of the two points with that x-coordinate, this checks the
+/// exact point `R`, i.e. that `s×R = z×G + r×Q`.
+///
+/// Accepts the following arguments:
+///
+/// - `q`: public key with which to verify the signature.
+/// - `z`: message digest to be verified. MUST BE OUTPUT OF A CRYPTOGRAPHICALLY SECURE DIGEST
+///        ALGORITHM!!!
+/// - `big_r`: ephemeral public point `R = k×G`.
+/// - `s`: the `s` component of the signature.
+///
+/// # Low-S Normalization
+///
+/// This is a low-level function that does *NOT* apply the `EcdsaCurve::NORMALIZE_S` checks.
+#[cfg(feature = "arithmetic")]
+pub fn verify_with_point<C>(
+    q: &ProjectivePoint<C>,
+    z: &FieldBytes<C>,
+    big_r: &AffinePoint<C>,
+    s: &Scalar<C>,
+) -> Result<()>
+where
+    C: EcdsaCurve + CurveArithmetic,
+{
+    use elliptic_curve::ff::Field;
+
+    let r = Scalar::<C>::reduce_bytes(&big_r.x());
+
+    // `AffinePoint::default()` is the identity
+    if *big_r == AffinePoint::<C>::default() || bool::from(r.is_zero() | s.is_zero()) {
+        return Err(Error::new());
+    }
+
+    let big_r = ProjectivePoint::<C>::from(*big_r);
+    let z = Scalar::<C>::reduce_bytes(z);
+    let rhs = ProjectivePoint::<C>::lincomb(&[(ProjectivePoint::<C>::generator(), z), (*q, r)]);
+
+    if big_r * *s == rhs {
+        Ok(())
+    } else {
+        Err(Error::new())
+    }
+}
+
+/// Verify the prehashed message against the provided ECDSA signature, using
+/// a precomputed inversion of its `s` component.
+///
+/// The caller MUST ensure `s_inv` is the multiplicative inverse of `sig.s()`.
+#[cfg(feature = "arithmetic")]
+pub(crate) fn verify_prehashed_with_s_inv<C>(
+    q: &ProjectivePoint<C>,
+    z: &FieldBytes<C>,
+    sig: &Signature<C>,
+    s_inv: &Scalar<C>,
+) -> Result<()>
+where
+    C: EcdsaCurve + CurveArithmetic,
+    SignatureSize<C>: ArraySize,
+{
+    let z = Scalar::<C>::reduce_bytes(z);
+    let (r, _) = sig.try_split_scalars()?;
+    let s_inv = *s_inv;
     let u1 = z * s_inv;
     let u2 = *r * s_inv;
     let x = ProjectivePoint::<C>::lincomb(&[(ProjectivePoint::<C>::generator(), u1), (*q, u2)])
@@ -246,6 +388,12 @@ mod tests {
         assert!(bits2field::<MockCurve>(b"").is_err());
     }
 
+    #[test]
+    fn bits2field_too_large() {
+        assert!(bits2field::<MockCurve>(&[0xAA; 64]).is_ok());
+        assert!(bits2field::<MockCurve>(&[0xAA; 65]).is_err());
+    }
+
     #[test]
     fn bits2field_size_less() {
         let prehash = hex!("AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA");
@@ -272,4 +420,16 @@ mod tests {
             &hex!("AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA")
         );
     }
+
+    /// A 64-byte prehash (e.g. two concatenated Keccak-256 outputs) used with a
+    /// 256-bit curve like secp256k1 must be handled identically to its leftmost
+    /// 256 bits.
+    #[test]
+    fn bits2field_size_greater_matches_leftmost_bits() {
+        let prehash = hex!("7F83B1657FF1FC53B92DC18148A1D65DFC2D4B1FA3D677284ADDD200126D9069E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855");
+        assert_eq!(
+            bits2field::<MockCurve>(&prehash).unwrap(),
+            bits2field::<MockCurve>(&prehash[..32]).unwrap()
+        );
+    }
 }
@@ -57,6 +57,7 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+mod p1363;
 mod recovery;
 
 #[cfg(feature = "der")]
@@ -70,7 +71,10 @@ mod signing;
 #[cfg(feature = "verifying")]
 mod verifying;
 
-pub use crate::recovery::RecoveryId;
+pub use crate::{p1363::P1363Signature, recovery::RecoveryId};
+
+#[cfg(feature = "arithmetic")]
+pub use crate::recovery::candidate_points_from_r;
 
 // Re-export the `elliptic-curve` crate (and select types)
 pub use elliptic_curve::{self, sec1::EncodedPoint, PrimeCurve};
@@ -79,11 +83,11 @@ pub use elliptic_curve::{self, sec1::EncodedPoint, PrimeCurve};
 pub use signature::{self, Error, Result, SignatureEncoding};
 
 #[cfg(feature = "signing")]
-pub use crate::signing::SigningKey;
+pub use crate::signing::{DomainSeparatedSigner, SigningKey};
 #[cfg(feature = "verifying")]
-pub use crate::verifying::VerifyingKey;
+pub use crate::verifying::{DomainSeparatedVerifier, VerifyingKey};
 
-use core::{fmt, ops::Add};
+use core::{fmt, ops::Add, str};
 use elliptic_curve::{
     array::{typenum::Unsigned, Array, ArraySize},
     FieldBytes, FieldBytesSize, ScalarPrimitive,
@@ -93,11 +97,11 @@ use elliptic_curve::{
 use alloc::vec::Vec;
 
 #[cfg(feature = "arithmetic")]
-use {
-    core::str,
-    elliptic_curve::{
-        scalar::IsHigh, subtle::ConditionallySelectable, CurveArithmetic, NonZeroScalar,
-    },
+use elliptic_curve::{
+    ops::{Invert, Reduce},
+    scalar::IsHigh,
+    subtle::ConditionallySelectable,
+    CurveArithmetic, NonZeroScalar, Scalar,
 };
 
 #[cfg(feature = "digest")]
@@ -116,7 +120,8 @@ use serdect::serde::{de, ser, Deserialize, Serialize};
 
 #[cfg(all(feature = "alloc", feature = "pkcs8"))]
 use elliptic_curve::pkcs8::spki::{
-    self, AlgorithmIdentifierOwned, DynAssociatedAlgorithmIdentifier,
+    self, der::Encode, AlgorithmIdentifierOwned, DynAssociatedAlgorithmIdentifier,
+    SignatureBitStringEncoding,
 };
 
 /// OID for ECDSA with SHA-224 digests.
@@ -171,6 +176,17 @@ pub trait EcdsaCurve: PrimeCurve {
     ///
     /// This is typically `false`. See [`Signature::normalize_s`] for more information.
     const NORMALIZE_S: bool;
+
+    /// Is the curve's scalar inversion (i.e. `Scalar::invert`) constant-time?
+    ///
+    /// Signing computes the inverse of the secret ephemeral scalar `k`, so
+    /// curves which use a variable-time inversion may leak information about
+    /// `k` via timing side channels unless countermeasures such as blinding
+    /// are used.
+    ///
+    /// There is deliberately no default: curve implementations must state
+    /// whether their scalar inversion is constant-time.
+    const CONSTANT_TIME_INVERT: bool;
 }
 
 /// Size of a fixed sized signature for the given elliptic curve.
@@ -238,6 +254,33 @@ where
             .and_then(Self::from_bytes)
     }
 
+    /// Parse a signature from a hexadecimal string containing the
+    /// concatenated big endian `r` and `s` components.
+    ///
+    /// Both upper and lower case hexadecimal digits are accepted. Like
+    /// [`Signature::from_bytes`], this checks that `r` and `s` are in the
+    /// range `1..n`, and does not require the `arithmetic` feature.
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let mut bytes = SignatureBytes::<C>::default();
+
+        if hex.len() != bytes.len() * 2 {
+            return Err(Error::new());
+        }
+
+        let nibble = |c: u8| match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            b'a'..=b'f' => Ok(c - b'a' + 10),
+            b'A'..=b'F' => Ok(c - b'A' + 10),
+            _ => Err(Error::new()),
+        };
+
+        for (byte, digits) in bytes.iter_mut().zip(hex.as_bytes().chunks_exact(2)) {
+            *byte = (nibble(digits[0])? << 4) | nibble(digits[1])?;
+        }
+
+        Self::from_bytes(&bytes)
+    }
+
     /// Parse a signature from ASN.1 DER.
     #[cfg(feature = "der")]
     pub fn from_der(bytes: &[u8]) -> Result<Self>
@@ -248,6 +291,21 @@ where
         der::Signature::<C>::try_from(bytes).and_then(Self::try_from)
     }
 
+    /// Parse a signature from ASN.1 BER, tolerating encodings which are not
+    /// valid DER.
+    ///
+    /// This is intended for interop with legacy systems which emit non-minimal
+    /// long form lengths or pad the `r` and `s` integers with extra leading
+    /// zeros. Indefinite lengths, negative integers, trailing data, and `r` or
+    /// `s` values which are out of range are still rejected.
+    ///
+    /// Prefer [`Signature::from_der`] whenever possible.
+    #[cfg(feature = "der")]
+    pub fn from_der_lax(bytes: &[u8]) -> Result<Self> {
+        let (r, s) = der::decode_ber_lax(bytes)?;
+        Self::from_scalars_trimmed(r, s)
+    }
+
     /// Create a [`Signature`] from the serialized `r` and `s` scalar values
     /// which comprise the signature.
     ///
@@ -267,6 +325,45 @@ where
         Ok(Self { r, s })
     }
 
+    /// Create a [`Signature`] from big endian `r` and `s` scalar values which
+    /// may be shorter than the field size, e.g. because leading zeros have
+    /// been stripped.
+    ///
+    /// This is intended for interop with systems which trim leading zeros from
+    /// each component of a signature, resulting in raw `r || s` signatures of
+    /// varying length. Each component is left padded with zeros to the field
+    /// size before being parsed as in [`Signature::from_scalars`].
+    ///
+    /// # Returns
+    /// - `Err(err)` if either component is longer than the field size, or is
+    ///   not in the valid range `1..n`.
+    pub fn from_scalars_trimmed(r: &[u8], s: &[u8]) -> Result<Self> {
+        Self::from_scalars(left_pad::<C>(r)?, left_pad::<C>(s)?)
+    }
+
+    /// Create a [`Signature`] from the serialized `r` and `s` scalar values
+    /// without checking that they are non-zero.
+    ///
+    /// The components must still be in the range `0..n`.
+    ///
+    /// # ⚠️ Warning
+    ///
+    /// This is intended for constructing edge-case signatures, e.g. to test
+    /// the robustness of verifiers. Verification and key recovery reject such
+    /// signatures with an error, however methods which return
+    /// `NonZeroScalar`s, such as `Signature::split_scalars`, will panic if
+    /// either component is zero. Use `Signature::split_scalars_unchecked` to
+    /// inspect them instead.
+    #[cfg(feature = "hazmat")]
+    pub fn from_scalars_unchecked(
+        r: impl Into<FieldBytes<C>>,
+        s: impl Into<FieldBytes<C>>,
+    ) -> Result<Self> {
+        let r = ScalarPrimitive::from_slice(&r.into()).map_err(|_| Error::new())?;
+        let s = ScalarPrimitive::from_slice(&s.into()).map_err(|_| Error::new())?;
+        Ok(Self { r, s })
+    }
+
     /// Split the signature into its `r` and `s` components, represented as bytes.
     pub fn split_bytes(&self) -> (FieldBytes<C>, FieldBytes<C>) {
         (self.r.to_bytes(), self.s.to_bytes())
@@ -305,6 +402,20 @@ where
     C: EcdsaCurve + CurveArithmetic,
     SignatureSize<C>: ArraySize,
 {
+    /// Create a [`Signature`] from big endian `r` and `s` integers of any
+    /// length, reducing them modulo the curve order `n`.
+    ///
+    /// This is useful when `r` and `s` come from a source which doesn't
+    /// produce fixed-width values, e.g. arbitrary-precision integers parsed
+    /// from decimal strings.
+    ///
+    /// # Returns
+    /// - `Ok(signature)` if the reduced `r` and `s` are both non-zero.
+    /// - `Err(err)` if either of them reduces to zero.
+    pub fn from_scalars_reduced(r: &[u8], s: &[u8]) -> Result<Self> {
+        Self::from_scalars(reduce_be_bytes::<C>(r), reduce_be_bytes::<C>(s))
+    }
+
     /// Get the `r` component of this signature
     pub fn r(&self) -> NonZeroScalar<C> {
         NonZeroScalar::new(self.r.into()).unwrap()
@@ -320,16 +431,69 @@ where
         (self.r(), self.s())
     }
 
+    /// Split the signature into its `r` and `s` scalars, returning an error
+    /// if either of them is zero, i.e. the signature was constructed using
+    /// `Signature::from_scalars_unchecked`.
+    pub(crate) fn try_split_scalars(&self) -> Result<(NonZeroScalar<C>, NonZeroScalar<C>)> {
+        let r = Option::from(NonZeroScalar::new(self.r.into())).ok_or_else(Error::new)?;
+        let s = Option::from(NonZeroScalar::new(self.s.into())).ok_or_else(Error::new)?;
+        Ok((r, s))
+    }
+
+    /// Split the signature into its `r` and `s` scalars without asserting
+    /// that they are non-zero.
+    ///
+    /// Unlike [`Signature::split_scalars`], this does not panic on signatures
+    /// constructed using [`Signature::from_scalars_unchecked`].
+    #[cfg(feature = "hazmat")]
+    pub fn split_scalars_unchecked(&self) -> (Scalar<C>, Scalar<C>) {
+        (self.r.into(), self.s.into())
+    }
+
     /// Normalize signature into "low S" form as described in
     /// [BIP 0062: Dealing with Malleability][1].
     ///
     /// [1]: https://github.com/bitcoin/bips/blob/master/bip-0062.mediawiki
     pub fn normalize_s(&self) -> Self {
         let mut result = self.clone();
-        let s_inv = ScalarPrimitive::from(-self.s());
-        result.s.conditional_assign(&s_inv, self.s.is_high());
+        result.normalize_s_mut();
         result
     }
+
+    /// Normalize signature into "low S" form in place as described in
+    /// [BIP 0062: Dealing with Malleability][1].
+    ///
+    /// Returns `true` if `s` was high and has been negated. The negation is
+    /// performed in constant time regardless of whether `s` was high.
+    ///
+    /// [1]: https://github.com/bitcoin/bips/blob/master/bip-0062.mediawiki
+    pub fn normalize_s_mut(&mut self) -> bool {
+        let is_high = self.s.is_high();
+        let s_neg = -self.s;
+        self.s.conditional_assign(&s_neg, is_high);
+        is_high.into()
+    }
+}
+
+/// Left pad a big endian integer with zeros to the size of a field element.
+fn left_pad<C: EcdsaCurve>(bytes: &[u8]) -> Result<FieldBytes<C>> {
+    let mut field_bytes = FieldBytes::<C>::default();
+    let offset = field_bytes
+        .len()
+        .checked_sub(bytes.len())
+        .ok_or_else(Error::new)?;
+    field_bytes[offset..].copy_from_slice(bytes);
+    Ok(field_bytes)
+}
+
+/// Reduce a big endian integer of any length modulo the curve order.
+#[cfg(feature = "arithmetic")]
+fn reduce_be_bytes<C: CurveArithmetic>(bytes: &[u8]) -> Scalar<C> {
+    use elliptic_curve::ff::Field;
+
+    bytes.iter().fold(Scalar::<C>::ZERO, |acc, &byte| {
+        (0..8).fold(acc, |acc, _| acc.double()) + Scalar::<C>::from(u64::from(byte))
+    })
 }
 
 impl<C> Copy for Signature<C>
@@ -422,39 +586,15 @@ where
     }
 }
 
-#[cfg(feature = "arithmetic")]
 impl<C> str::FromStr for Signature<C>
 where
-    C: EcdsaCurve + CurveArithmetic,
+    C: EcdsaCurve,
     SignatureSize<C>: ArraySize,
 {
     type Err = Error;
 
     fn from_str(hex: &str) -> Result<Self> {
-        if hex.as_bytes().len() != C::FieldBytesSize::USIZE * 4 {
-            return Err(Error::new());
-        }
-
-        // This check is mainly to ensure `hex.split_at` below won't panic
-        if !hex
-            .as_bytes()
-            .iter()
-            .all(|&byte| matches!(byte, b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z'))
-        {
-            return Err(Error::new());
-        }
-
-        let (r_hex, s_hex) = hex.split_at(C::FieldBytesSize::USIZE * 2);
-
-        let r = r_hex
-            .parse::<NonZeroScalar<C>>()
-            .map_err(|_| Error::new())?;
-
-        let s = s_hex
-            .parse::<NonZeroScalar<C>>()
-            .map_err(|_| Error::new())?;
-
-        Self::from_scalars(r, s)
+        Self::from_hex(hex)
     }
 }
 
@@ -521,6 +661,71 @@ where
     }
 }
 
+/// Detect whether two signatures were produced using the same ephemeral
+/// scalar (`k`), a.k.a. nonce reuse.
+///
+/// Signatures which share the same `r` component were computed from the same
+/// point 𝑹 = 𝑘×𝑮, which is the telltale sign of nonce reuse. If the two
+/// signatures were produced by the same key over different messages, the
+/// private key can be recovered from them (see [`recover_key_from_reused_nonce`]).
+///
+/// This is a defensive/forensic utility intended for use by e.g. security
+/// scanners auditing a corpus of signatures.
+pub fn detect_nonce_reuse<C>(sig1: &Signature<C>, sig2: &Signature<C>) -> bool
+where
+    C: EcdsaCurve,
+{
+    sig1.r == sig2.r
+}
+
+/// Recover the private scalar from two signatures produced by the same key
+/// which reused the same ephemeral scalar (`k`) to sign different messages.
+///
+/// Accepts the following arguments:
+///
+/// - `sig1`, `sig2`: signatures which share the same `r` component.
+/// - `z1`, `z2`: message digests signed by `sig1` and `sig2` respectively, as
+///   computed by [`hazmat::bits2field`].
+///
+/// Returns `None` if the signatures don't share an `r` component, or if the
+/// private scalar can't be solved for (e.g. both signatures are over the
+/// same message).
+///
+/// Note that if either signature has been low-S normalized (see
+/// [`Signature::normalize_s`]), it may be necessary to also try the
+/// non-normalized form of `s`.
+///
+/// This is a defensive/forensic utility intended for use by e.g. security
+/// auditing tools.
+#[cfg(feature = "arithmetic")]
+pub fn recover_key_from_reused_nonce<C>(
+    sig1: &Signature<C>,
+    z1: &FieldBytes<C>,
+    sig2: &Signature<C>,
+    z2: &FieldBytes<C>,
+) -> Option<NonZeroScalar<C>>
+where
+    C: EcdsaCurve + CurveArithmetic,
+    SignatureSize<C>: ArraySize,
+{
+    if !detect_nonce_reuse(sig1, sig2) {
+        return None;
+    }
+
+    let z1 = <Scalar<C> as Reduce<C::Uint>>::reduce_bytes(z1);
+    let z2 = <Scalar<C> as Reduce<C::Uint>>::reduce_bytes(z2);
+    let (r, s1) = sig1.try_split_scalars().ok()?;
+    let (_, s2) = sig2.try_split_scalars().ok()?;
+
+    // 𝑘 = (𝒛₁ - 𝒛₂) / (𝒔₁ - 𝒔₂)
+    let s_diff_inv = Option::<Scalar<C>>::from((*s1 - *s2).invert())?;
+    let k = (z1 - z2) * s_diff_inv;
+
+    // 𝒅 = (𝒔₁𝑘 - 𝒛₁) / 𝒓
+    let d = (*s1 * k - z1) * *r.invert();
+    NonZeroScalar::new(d).into()
+}
+
 /// An extended [`Signature`] type which is parameterized by an
 /// `ObjectIdentifier` which identifies the ECDSA variant used by a
 /// particular signature.
@@ -554,19 +759,17 @@ where
 {
     /// Create a new signature with an explicitly provided OID.
     ///
-    /// OID must begin with `1.2.840.10045.4`, the [RFC5758] OID prefix for
-    /// ECDSA variants.
+    /// OID must be an arc directly below `1.2.840.10045.4.3`, the [RFC5758]
+    /// OID prefix for ECDSA-with-SHA2 variants.
+    ///
+    /// The OID is carried as-is and does not need to correspond to a digest
+    /// which is compiled in, so signatures can be stored and re-encoded
+    /// without losing their OID.
     ///
     /// [RFC5758]: https://www.rfc-editor.org/rfc/rfc5758#section-3.2
     pub fn new(signature: Signature<C>, oid: ObjectIdentifier) -> Result<Self> {
-        // TODO(tarcieri): use `ObjectIdentifier::starts_with`
-        for (arc1, arc2) in ObjectIdentifier::new_unwrap("1.2.840.10045.4.3")
-            .arcs()
-            .zip(oid.arcs())
-        {
-            if arc1 != arc2 {
-                return Err(Error::new());
-            }
+        if oid.parent() != Some(ObjectIdentifier::new_unwrap("1.2.840.10045.4.3")) {
+            return Err(Error::new());
         }
 
         Ok(Self { signature, oid })
@@ -746,6 +949,38 @@ where
     }
 }
 
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+impl<C> SignatureWithOid<C>
+where
+    C: EcdsaCurve,
+    der::MaxSize<C>: ArraySize,
+    <FieldBytesSize<C> as Add>::Output: Add<der::MaxOverhead> + ArraySize,
+{
+    /// Serialize this signature as the `signatureAlgorithm` and
+    /// `signatureValue` fields of an X.509 certificate ([RFC5280 § 4.1.1]).
+    ///
+    /// The output is the DER encoding of an `AlgorithmIdentifier` containing
+    /// this signature's OID with absent parameters (as required for ECDSA by
+    /// [RFC5758 § 3.2]), followed by a `BIT STRING` containing the ASN.1 DER
+    /// encoded signature.
+    ///
+    /// [RFC5280 § 4.1.1]: https://www.rfc-editor.org/rfc/rfc5280#section-4.1.1
+    /// [RFC5758 § 3.2]: https://www.rfc-editor.org/rfc/rfc5758#section-3.2
+    pub fn to_der_with_algorithm_identifier(&self) -> Result<Vec<u8>> {
+        let mut out = self
+            .algorithm_identifier()
+            .and_then(|algorithm| Ok(algorithm.to_der()?))
+            .map_err(|_| Error::new())?;
+        let signature = self
+            .to_der()
+            .to_bitstring()
+            .and_then(|bit_string| bit_string.to_der())
+            .map_err(|_| Error::new())?;
+        out.extend_from_slice(&signature);
+        Ok(out)
+    }
+}
+
 #[cfg(all(feature = "alloc", feature = "pkcs8"))]
 impl<C> DynAssociatedAlgorithmIdentifier for SignatureWithOid<C>
 where
@@ -770,3 +1005,49 @@ const fn ecdsa_oid_for_digest(digest_oid: ObjectIdentifier) -> Option<ObjectIden
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{EcdsaCurve, Signature};
+    use elliptic_curve::{bigint::U256, consts::U32, Curve, FieldBytesEncoding, PrimeCurve};
+
+    /// Curve without an arithmetic implementation, so these tests also run
+    /// when the `arithmetic` feature is disabled.
+    #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+    struct TestCurve;
+
+    impl Curve for TestCurve {
+        type FieldBytesSize = U32;
+        type Uint = U256;
+
+        const ORDER: U256 =
+            U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+    }
+
+    impl PrimeCurve for TestCurve {}
+
+    impl FieldBytesEncoding<TestCurve> for U256 {}
+
+    impl EcdsaCurve for TestCurve {
+        const NORMALIZE_S: bool = false;
+        const CONSTANT_TIME_INVERT: bool = false;
+    }
+
+    #[test]
+    fn from_hex() {
+        let mut bytes = [0x11; 64];
+        bytes[32..].fill(0xab);
+        let expected = Signature::<TestCurve>::from_bytes(&bytes.into()).unwrap();
+
+        let lower = "1111111111111111111111111111111111111111111111111111111111111111abababababababababababababababababababababababababababababababab";
+        let upper = "1111111111111111111111111111111111111111111111111111111111111111ABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABAB";
+        assert_eq!(Signature::<TestCurve>::from_hex(lower).unwrap(), expected);
+        assert_eq!(Signature::<TestCurve>::from_hex(upper).unwrap(), expected);
+
+        // wrong length
+        assert!(Signature::<TestCurve>::from_hex(&lower[..126]).is_err());
+        // non-hex digit
+        let bad = "1111111111111111111111111111111111111111111111111111111111111111abababababababababababababababababababababababababababababababag";
+        assert!(Signature::<TestCurve>::from_hex(bad).is_err());
+    }
+}
@@ -0,0 +1,138 @@
+//! IEEE P1363 signature encoding support.
+
+use crate::{EcdsaCurve, Error, Result, Signature, SignatureBytes, SignatureSize};
+use core::fmt;
+use elliptic_curve::array::ArraySize;
+use signature::SignatureEncoding;
+
+#[cfg(feature = "der")]
+use {crate::der, core::ops::Add, elliptic_curve::FieldBytesSize};
+
+/// ECDSA signature which is explicitly encoded in the fixed-width
+/// IEEE P1363 format, i.e. the big endian `r` and `s` components concatenated
+/// as `r || s`.
+///
+/// This is the same encoding as [`Signature`], but provides a distinct type
+/// which can be used alongside [`der::Signature`] by code which is generic
+/// over the signature encoding.
+#[derive(Clone, Eq, PartialEq)]
+pub struct P1363Signature<C: EcdsaCurve>(Signature<C>);
+
+impl<C> P1363Signature<C>
+where
+    C: EcdsaCurve,
+    SignatureSize<C>: ArraySize,
+{
+    /// Parse a signature from fixed-width IEEE P1363 bytes.
+    pub fn from_bytes(bytes: &SignatureBytes<C>) -> Result<Self> {
+        Signature::from_bytes(bytes).map(Self)
+    }
+
+    /// Parse a signature from a byte slice containing an IEEE P1363 encoded
+    /// signature.
+    pub fn from_slice(slice: &[u8]) -> Result<Self> {
+        Signature::from_slice(slice).map(Self)
+    }
+
+    /// Borrow the inner [`Signature`].
+    pub fn signature(&self) -> &Signature<C> {
+        &self.0
+    }
+
+    /// Serialize this signature as IEEE P1363 bytes.
+    pub fn to_bytes(&self) -> SignatureBytes<C> {
+        self.0.to_bytes()
+    }
+}
+
+impl<C> Copy for P1363Signature<C>
+where
+    C: EcdsaCurve,
+    SignatureSize<C>: ArraySize,
+    <SignatureSize<C> as ArraySize>::ArrayType<u8>: Copy,
+{
+}
+
+impl<C> fmt::Debug for P1363Signature<C>
+where
+    C: EcdsaCurve,
+    SignatureSize<C>: ArraySize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ecdsa::P1363Signature<{:?}>({:X})", C::default(), self.0)
+    }
+}
+
+impl<C> From<Signature<C>> for P1363Signature<C>
+where
+    C: EcdsaCurve,
+{
+    fn from(signature: Signature<C>) -> P1363Signature<C> {
+        Self(signature)
+    }
+}
+
+impl<C> From<P1363Signature<C>> for Signature<C>
+where
+    C: EcdsaCurve,
+{
+    fn from(signature: P1363Signature<C>) -> Signature<C> {
+        signature.0
+    }
+}
+
+impl<C> From<P1363Signature<C>> for SignatureBytes<C>
+where
+    C: EcdsaCurve,
+    SignatureSize<C>: ArraySize,
+{
+    fn from(signature: P1363Signature<C>) -> SignatureBytes<C> {
+        signature.to_bytes()
+    }
+}
+
+impl<C> SignatureEncoding for P1363Signature<C>
+where
+    C: EcdsaCurve,
+    SignatureSize<C>: ArraySize,
+{
+    type Repr = SignatureBytes<C>;
+}
+
+impl<C> TryFrom<&[u8]> for P1363Signature<C>
+where
+    C: EcdsaCurve,
+    SignatureSize<C>: ArraySize,
+{
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self> {
+        Self::from_slice(slice)
+    }
+}
+
+#[cfg(feature = "der")]
+impl<C> From<P1363Signature<C>> for der::Signature<C>
+where
+    C: EcdsaCurve,
+    der::MaxSize<C>: ArraySize,
+    <FieldBytesSize<C> as Add>::Output: Add<der::MaxOverhead> + ArraySize,
+{
+    fn from(signature: P1363Signature<C>) -> der::Signature<C> {
+        signature.0.to_der()
+    }
+}
+
+#[cfg(feature = "der")]
+impl<C> TryFrom<der::Signature<C>> for P1363Signature<C>
+where
+    C: EcdsaCurve,
+    der::MaxSize<C>: ArraySize,
+    <FieldBytesSize<C> as Add>::Output: Add<der::MaxOverhead> + ArraySize,
+{
+    type Error = Error;
+
+    fn try_from(signature: der::Signature<C>) -> Result<Self> {
+        Signature::try_from(signature).map(Self)
+    }
+}
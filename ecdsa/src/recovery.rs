@@ -2,22 +2,29 @@
 
 use crate::{Error, Result};
 
+#[cfg(feature = "arithmetic")]
+use {
+    crate::EcdsaCurve,
+    elliptic_curve::{
+        bigint::CheckedAdd, point::DecompressPoint, AffinePoint, CurveArithmetic, FieldBytes,
+        FieldBytesEncoding,
+    },
+};
+
 #[cfg(feature = "signing")]
 use {
     crate::{hazmat::sign_prehashed_rfc6979, SigningKey},
     elliptic_curve::subtle::CtOption,
-    signature::{hazmat::PrehashSigner, DigestSigner, Signer},
+    signature::{hazmat::PrehashSigner, rand_core::CryptoRngCore, DigestSigner, Signer},
 };
 
 #[cfg(feature = "verifying")]
 use {
     crate::{hazmat::verify_prehashed, VerifyingKey},
     elliptic_curve::{
-        bigint::CheckedAdd,
         ops::{LinearCombination, Reduce},
-        point::DecompressPoint,
         sec1::{self, FromEncodedPoint, ToEncodedPoint},
-        AffinePoint, FieldBytesEncoding, FieldBytesSize, Group, PrimeField, ProjectivePoint,
+        FieldBytesSize, Group, PrimeField, ProjectivePoint,
     },
 };
 
@@ -25,12 +32,20 @@ use {
 use {
     crate::{
         hazmat::{bits2field, DigestPrimitive},
-        EcdsaCurve, Signature, SignatureSize,
+        Signature, SignatureSize,
     },
-    elliptic_curve::{array::ArraySize, ops::Invert, CurveArithmetic, Scalar},
+    elliptic_curve::{array::ArraySize, ops::Invert, Scalar},
     signature::digest::Digest,
 };
 
+/// Maximum number of signing attempts made by
+/// [`SigningKey::sign_prehash_recoverable_with_parity`] before giving up.
+///
+/// Each attempt has a ~50% chance of producing the requested parity, so the
+/// probability of exhausting all attempts is ~2⁻⁶⁴.
+#[cfg(feature = "signing")]
+const MAX_PARITY_ATTEMPTS: usize = 64;
+
 /// Recovery IDs, a.k.a. "recid".
 ///
 /// This is an integer value `0`, `1`, `2`, or `3` included along with a
@@ -79,6 +94,10 @@ impl RecoveryId {
     }
 
     /// Convert this [`RecoveryId`] into a `u8`.
+    ///
+    /// The result is always in the range `0..=3`. Protocols which use a
+    /// different convention for the recovery byte (e.g. Ethereum's legacy
+    /// `v = 27 + recid`) should apply their offset to this value.
     pub const fn to_byte(self) -> u8 {
         self.0
     }
@@ -171,6 +190,48 @@ impl From<RecoveryId> for u8 {
     }
 }
 
+/// Lift the `r` component of a signature to the candidate points `R` on the
+/// curve whose affine x-coordinate reduces to `r`.
+///
+/// Yields up to four points in [`RecoveryId`] order, each paired with the
+/// [`RecoveryId`] which selects it: the two points with x-coordinate `r` (even
+/// y first), followed by the two points with x-coordinate `r + n` in the rare
+/// case where that value is still a valid field element.
+///
+/// This is the first step of public key recovery, and can be used to build
+/// custom recovery routines when no [`RecoveryId`] is available.
+#[cfg(feature = "arithmetic")]
+pub fn candidate_points_from_r<C>(
+    r: &FieldBytes<C>,
+) -> impl Iterator<Item = (RecoveryId, AffinePoint<C>)>
+where
+    C: EcdsaCurve + CurveArithmetic,
+    AffinePoint<C>: DecompressPoint<C>,
+{
+    let r = r.clone();
+
+    (0..=RecoveryId::MAX)
+        .map(RecoveryId)
+        .filter_map(move |recovery_id| Some((recovery_id, candidate_point::<C>(&r, recovery_id)?)))
+}
+
+/// Compute the point `R` selected by `recovery_id` among the candidates for `r`.
+#[cfg(feature = "arithmetic")]
+fn candidate_point<C>(r: &FieldBytes<C>, recovery_id: RecoveryId) -> Option<AffinePoint<C>>
+where
+    C: EcdsaCurve + CurveArithmetic,
+    AffinePoint<C>: DecompressPoint<C>,
+{
+    let x = if recovery_id.is_x_reduced() {
+        Option::<C::Uint>::from(C::Uint::decode_field_bytes(r).checked_add(&C::ORDER))?
+            .encode_field_bytes()
+    } else {
+        r.clone()
+    };
+
+    AffinePoint::<C>::decompress(&x, u8::from(recovery_id.is_y_odd()).into()).into()
+}
+
 #[cfg(feature = "signing")]
 impl<C> SigningKey<C>
 where
@@ -197,6 +258,38 @@ where
     pub fn sign_recoverable(&self, msg: &[u8]) -> Result<(Signature<C>, RecoveryId)> {
         self.sign_digest_recoverable(C::Digest::new_with_prefix(msg))
     }
+
+    /// Sign the given message prehash using hedged signing (i.e. RFC6979 with
+    /// added entropy from `rng`), retrying until the y-coordinate parity
+    /// encoded in the returned [`RecoveryId`] matches the requested one.
+    ///
+    /// This is useful for protocols which require a fixed recovery ID parity,
+    /// e.g. so the signature fits a fixed-size format.
+    ///
+    /// Returns an error if a signature with the requested parity couldn't be
+    /// produced after a bounded number of attempts (which is astronomically
+    /// unlikely).
+    pub fn sign_prehash_recoverable_with_parity(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        prehash: &[u8],
+        want_even_y: bool,
+    ) -> Result<(Signature<C>, RecoveryId)> {
+        let z = bits2field::<C>(prehash)?;
+        let mut ad = FieldBytes::<C>::default();
+
+        for _ in 0..MAX_PARITY_ATTEMPTS {
+            rng.fill_bytes(&mut ad);
+            let (signature, recovery_id) =
+                sign_prehashed_rfc6979::<C, C::Digest>(self.as_nonzero_scalar(), &z, &ad)?;
+
+            if recovery_id.is_y_odd() != want_even_y {
+                return Ok((signature, recovery_id));
+            }
+        }
+
+        Err(Error::new())
+    }
 }
 
 #[cfg(feature = "signing")]
@@ -299,25 +392,12 @@ where
         signature: &Signature<C>,
         recovery_id: RecoveryId,
     ) -> Result<Self> {
-        let (r, s) = signature.split_scalars();
+        let (r, s) = signature.try_split_scalars()?;
         let z = <Scalar<C> as Reduce<C::Uint>>::reduce_bytes(&bits2field::<C>(prehash)?);
 
-        let r_bytes = if recovery_id.is_x_reduced() {
-            Option::<C::Uint>::from(
-                C::Uint::decode_field_bytes(&r.to_repr()).checked_add(&C::ORDER),
-            )
+        let R: ProjectivePoint<C> = candidate_point::<C>(&r.to_repr(), recovery_id)
             .ok_or_else(Error::new)?
-            .encode_field_bytes()
-        } else {
-            r.to_repr()
-        };
-
-        let R: ProjectivePoint<C> = Option::<AffinePoint<C>>::from(AffinePoint::<C>::decompress(
-            &r_bytes,
-            u8::from(recovery_id.is_y_odd()).into(),
-        ))
-        .ok_or_else(Error::new)?
-        .into();
+            .into();
 
         let r_inv = *r.invert();
         let u1 = -(r_inv * z);
@@ -358,6 +438,21 @@ mod tests {
         assert_eq!(RecoveryId::try_from(3).unwrap().is_x_reduced(), true);
     }
 
+    #[test]
+    fn from_byte_to_byte() {
+        for (is_y_odd, is_x_reduced) in [(false, false), (true, false), (false, true), (true, true)]
+        {
+            let id = RecoveryId::new(is_y_odd, is_x_reduced);
+            let byte = id.to_byte();
+            assert_eq!(RecoveryId::from_byte(byte), Some(id));
+            assert_eq!(u8::from(id), byte);
+            assert_eq!(id.is_y_odd(), is_y_odd);
+            assert_eq!(id.is_x_reduced(), is_x_reduced);
+        }
+
+        assert_eq!(RecoveryId::from_byte(RecoveryId::MAX + 1), None);
+    }
+
     #[test]
     fn is_y_odd() {
         assert_eq!(RecoveryId::try_from(0).unwrap().is_y_odd(), false);
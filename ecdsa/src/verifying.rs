@@ -7,10 +7,12 @@ use crate::{
 use core::{cmp::Ordering, fmt::Debug};
 use elliptic_curve::{
     array::ArraySize,
+    ff::Field,
     point::PointCompression,
     scalar::IsHigh,
     sec1::{self, CompressedPoint, EncodedPoint, FromEncodedPoint, ToEncodedPoint},
-    AffinePoint, CurveArithmetic, FieldBytesSize, ProjectivePoint, PublicKey,
+    subtle::ConstantTimeEq,
+    AffinePoint, CurveArithmetic, FieldBytes, FieldBytesSize, ProjectivePoint, PublicKey, Scalar,
 };
 use signature::{
     digest::{Digest, FixedOutput},
@@ -40,6 +42,9 @@ use elliptic_curve::pkcs8::{
 #[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
 
+#[cfg(feature = "eth")]
+use sha3::Keccak256;
+
 #[cfg(feature = "sha2")]
 use {
     crate::{
@@ -76,6 +81,19 @@ use elliptic_curve::pkcs8::EncodePublicKey;
 ///
 /// The serialization leverages the encoding used by the [`PublicKey`] type,
 /// which is a binary-oriented ASN.1 DER encoding.
+///
+/// # `pkcs8` support
+///
+/// When the `pkcs8` feature of this crate is enabled, keys can be decoded from
+/// an X.509 `SubjectPublicKeyInfo` (e.g. one extracted from a certificate)
+/// using the `DecodePublicKey` trait, which checks that the algorithm is
+/// `id-ecPublicKey` and that the curve parameters match `C`.
+///
+/// # Equality
+///
+/// [`VerifyingKey`]s are compared by their underlying [`AffinePoint`], not by
+/// any particular encoding. Keys decoded from the compressed and uncompressed
+/// SEC1 encodings of the same point are equal.
 #[derive(Clone, Debug)]
 pub struct VerifyingKey<C>
 where
@@ -138,6 +156,193 @@ where
     pub fn as_affine(&self) -> &AffinePoint<C> {
         self.inner.as_affine()
     }
+
+    /// Compute the Ethereum-style address of this [`VerifyingKey`].
+    ///
+    /// This is the last 20 bytes of the Keccak-256 hash of the uncompressed
+    /// SEC1 encoding of the public key with its leading `0x04` tag byte
+    /// removed, i.e. `keccak256(x || y)[12..]`.
+    ///
+    /// Only meaningful for secp256k1 keys.
+    #[cfg(feature = "eth")]
+    pub fn to_eth_address(&self) -> [u8; 20] {
+        let point = self.to_encoded_point(false);
+        let digest = Keccak256::digest(&point.as_bytes()[1..]);
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&digest[12..]);
+        address
+    }
+
+    /// Get a [`DomainSeparatedVerifier`] which verifies signatures over
+    /// `tag || msg`, where `tag` is a protocol-specific domain separation tag.
+    ///
+    /// See [`SigningKey::domain_separated`][crate::SigningKey::domain_separated].
+    pub fn domain_separated(&self, tag: &'static [u8]) -> DomainSeparatedVerifier<'_, C> {
+        DomainSeparatedVerifier {
+            verifying_key: self,
+            tag,
+        }
+    }
+}
+
+/// ECDSA verifier which prepends a domain separation tag to every message.
+///
+/// Messages are hashed with the curve's [`DigestPrimitive`] as `tag || msg`.
+///
+/// Constructed using [`VerifyingKey::domain_separated`].
+#[derive(Clone, Copy, Debug)]
+pub struct DomainSeparatedVerifier<'a, C>
+where
+    C: EcdsaCurve + CurveArithmetic,
+{
+    verifying_key: &'a VerifyingKey<C>,
+    tag: &'static [u8],
+}
+
+impl<'a, C> DomainSeparatedVerifier<'a, C>
+where
+    C: EcdsaCurve + CurveArithmetic,
+{
+    /// Get the domain separation tag used by this verifier.
+    pub fn tag(&self) -> &'static [u8] {
+        self.tag
+    }
+
+    /// Borrow the inner [`VerifyingKey`].
+    pub fn verifying_key(&self) -> &'a VerifyingKey<C> {
+        self.verifying_key
+    }
+}
+
+impl<C> Verifier<Signature<C>> for DomainSeparatedVerifier<'_, C>
+where
+    C: EcdsaCurve + CurveArithmetic + DigestPrimitive,
+    SignatureSize<C>: ArraySize,
+{
+    fn verify(&self, msg: &[u8], signature: &Signature<C>) -> Result<()> {
+        self.verifying_key.verify_digest(
+            C::Digest::new_with_prefix(self.tag).chain_update(msg),
+            signature,
+        )
+    }
+}
+
+impl<C> VerifyingKey<C>
+where
+    C: EcdsaCurve + CurveArithmetic,
+    SignatureSize<C>: ArraySize,
+{
+    /// Verify a prehashed message which has already been converted into a
+    /// serialized field element, e.g. using [`bits2field`].
+    ///
+    /// This is equivalent to [`PrehashVerifier::verify_prehash`], but skips
+    /// the `bits2field` conversion of the prehash. Like `verify_prehash`, it
+    /// rejects high-S signatures if `C::NORMALIZE_S` is set.
+    pub fn verify_field_prehash(&self, z: &FieldBytes<C>, signature: &Signature<C>) -> Result<()> {
+        let (_, s) = signature.try_split_scalars()?;
+
+        if C::NORMALIZE_S && s.is_high().into() {
+            return Err(Error::new());
+        }
+
+        hazmat::verify_prehashed::<C>(
+            &ProjectivePoint::<C>::from(*self.inner.as_affine()),
+            z,
+            signature,
+        )
+    }
+
+    /// Verify a prehashed message against the provided signature, using a
+    /// precomputed inversion `s_inv` of the signature's `s` component instead
+    /// of computing it.
+    ///
+    /// Returns an error if `s_inv × s ≠ 1` (checked in constant time), or if
+    /// the signature is otherwise invalid. Like `verify_prehash`, this rejects
+    /// high-S signatures if `C::NORMALIZE_S` is set.
+    pub fn verify_prehash_with_sinv(
+        &self,
+        prehash: &[u8],
+        signature: &Signature<C>,
+        s_inv: &Scalar<C>,
+    ) -> Result<()> {
+        let (_, s) = signature.try_split_scalars()?;
+
+        if C::NORMALIZE_S && s.is_high().into() {
+            return Err(Error::new());
+        }
+
+        if !bool::from((*s * s_inv).ct_eq(&Scalar::<C>::ONE)) {
+            return Err(Error::new());
+        }
+
+        hazmat::verify_prehashed_with_s_inv::<C>(
+            &ProjectivePoint::<C>::from(*self.inner.as_affine()),
+            &bits2field::<C>(prehash)?,
+            signature,
+            s_inv,
+        )
+    }
+
+    /// Verify a signature over a message whose [`Digest`] has already been
+    /// updated with the message contents, e.g. incrementally in chunks.
+    ///
+    /// The digest is finalized and the result verified as a prehash. This is
+    /// equivalent to [`DigestVerifier::verify_digest`], but as an inherent
+    /// method it doesn't require naming the trait or signature type.
+    pub fn verify_with_digest_instance<D>(&self, digest: D, signature: &Signature<C>) -> Result<()>
+    where
+        D: Digest + FixedOutput,
+    {
+        self.verify_field_prehash(&bits2field::<C>(&digest.finalize())?, signature)
+    }
+}
+
+impl<C> VerifyingKey<C>
+where
+    C: EcdsaCurve + CurveArithmetic + DigestPrimitive,
+    SignatureSize<C>: ArraySize,
+{
+    /// Verify a signature against several candidate encodings of a message,
+    /// hashing each with `C::Digest`.
+    ///
+    /// Returns the index of the first candidate the signature is valid for,
+    /// or `None` if it doesn't verify for any of them.
+    pub fn verify_any_message(
+        &self,
+        candidates: &[&[u8]],
+        signature: &Signature<C>,
+    ) -> Option<usize> {
+        candidates
+            .iter()
+            .position(|msg| self.verify(msg, signature).is_ok())
+    }
+
+    /// Verify a batch of `(verifying key, message, signature)` items, hashing
+    /// each message with `C::Digest`.
+    ///
+    /// Returns `Ok(())` if every signature in the batch is valid for its key
+    /// and message, or `Err(index)` with the index of the first item which
+    /// isn't. A batch is accepted if and only if each item would pass
+    /// [`Verifier::verify`] individually.
+    ///
+    /// # Performance
+    ///
+    /// This provides no speedup over verifying each item in turn, which is
+    /// exactly what it does. Unlike EdDSA, ECDSA signatures only carry `r`,
+    /// the x-coordinate of the ephemeral point `R` reduced mod `n`, so a
+    /// random linear combination of the verification equations can't be
+    /// checked without first resolving which of the candidate points was
+    /// used for each signature, which costs as much as verifying it.
+    pub fn verify_batch(
+        items: &[(VerifyingKey<C>, &[u8], &Signature<C>)],
+    ) -> core::result::Result<(), usize> {
+        items
+            .iter()
+            .position(|(verifying_key, msg, signature)| {
+                verifying_key.verify(msg, *signature).is_err()
+            })
+            .map_or(Ok(()), Err)
+    }
 }
 
 //
@@ -161,15 +366,7 @@ where
     SignatureSize<C>: ArraySize,
 {
     fn verify_prehash(&self, prehash: &[u8], signature: &Signature<C>) -> Result<()> {
-        if C::NORMALIZE_S && signature.s().is_high().into() {
-            return Err(Error::new());
-        }
-
-        hazmat::verify_prehashed::<C>(
-            &ProjectivePoint::<C>::from(*self.inner.as_affine()),
-            &bits2field::<C>(prehash)?,
-            signature,
-        )
+        self.verify_field_prehash(&bits2field::<C>(prehash)?, signature)
     }
 }
 
@@ -184,12 +381,19 @@ where
 }
 
 #[cfg(feature = "sha2")]
-impl<C> Verifier<SignatureWithOid<C>> for VerifyingKey<C>
+impl<C> VerifyingKey<C>
 where
-    C: EcdsaCurve + CurveArithmetic + DigestPrimitive,
+    C: EcdsaCurve + CurveArithmetic,
     SignatureSize<C>: ArraySize,
 {
-    fn verify(&self, msg: &[u8], sig: &SignatureWithOid<C>) -> Result<()> {
+    /// Verify the given message against a [`SignatureWithOid`], hashing the
+    /// message using the SHA-2 digest identified by the signature's OID.
+    ///
+    /// Supports the ECDSA OIDs defined in [RFC5758 § 3.2], i.e. SHA-224,
+    /// SHA-256, SHA-384, and SHA-512. Returns an error for any other OID.
+    ///
+    /// [RFC5758 § 3.2]: https://www.rfc-editor.org/rfc/rfc5758#section-3.2
+    pub fn verify_with_oid(&self, msg: &[u8], sig: &SignatureWithOid<C>) -> Result<()> {
         match sig.oid() {
             ECDSA_SHA224_OID => self.verify_prehash(&Sha224::digest(msg), sig.signature()),
             ECDSA_SHA256_OID => self.verify_prehash(&Sha256::digest(msg), sig.signature()),
@@ -200,6 +404,17 @@ where
     }
 }
 
+#[cfg(feature = "sha2")]
+impl<C> Verifier<SignatureWithOid<C>> for VerifyingKey<C>
+where
+    C: EcdsaCurve + CurveArithmetic + DigestPrimitive,
+    SignatureSize<C>: ArraySize,
+{
+    fn verify(&self, msg: &[u8], sig: &SignatureWithOid<C>) -> Result<()> {
+        self.verify_with_oid(msg, sig)
+    }
+}
+
 #[cfg(feature = "der")]
 impl<C, D> DigestVerifier<D, der::Signature<C>> for VerifyingKey<C>
 where
@@ -416,7 +631,7 @@ where
 #[cfg(feature = "pkcs8")]
 impl<C> TryFrom<pkcs8::SubjectPublicKeyInfoRef<'_>> for VerifyingKey<C>
 where
-    C: EcdsaCurve + AssociatedOid + CurveArithmetic + PointCompression,
+    C: EcdsaCurve + AssociatedOid + CurveArithmetic,
     AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
     FieldBytesSize<C>: sec1::ModulusSize,
 {
@@ -442,7 +657,7 @@ where
 #[cfg(feature = "pem")]
 impl<C> FromStr for VerifyingKey<C>
 where
-    C: EcdsaCurve + AssociatedOid + CurveArithmetic + PointCompression,
+    C: EcdsaCurve + AssociatedOid + CurveArithmetic,
     AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
     FieldBytesSize<C>: sec1::ModulusSize,
 {
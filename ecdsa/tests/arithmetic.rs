@@ -0,0 +1,449 @@
+//! Tests which need real curve arithmetic, using the P-256 implementation in
+//! the `p256` module.
+
+#![cfg(all(feature = "signing", feature = "verifying"))]
+
+mod p256;
+
+use ecdsa::{
+    hazmat,
+    signature::{rand_core, Signer, Verifier},
+};
+use elliptic_curve::{
+    bigint::U256,
+    ff::PrimeField,
+    ops::{Invert, Reduce},
+    point::AffineCoordinates,
+    scalar::IsHigh,
+};
+use hex_literal::hex;
+use p256::{FieldBytes, ProjectivePoint, P256};
+use rand_core::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+
+type NonZeroScalar = elliptic_curve::NonZeroScalar<P256>;
+type Signature = ecdsa::Signature<P256>;
+type SigningKey = ecdsa::SigningKey<P256>;
+
+/// RFC6979 A.2.5 P-256 private key `x`.
+const SECRET_KEY: [u8; 32] =
+    hex!("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+
+/// RFC6979 A.2.5 P-256 public key `U = xG`: `(Ux, Uy)`.
+const PUBLIC_KEY: ([u8; 32], [u8; 32]) = (
+    hex!("60FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6"),
+    hex!("7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299"),
+);
+
+/// RFC6979 A.2.5 ephemeral scalar `k` with SHA-256, message = "sample".
+const SAMPLE_K: [u8; 32] = hex!("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60");
+
+/// RFC6979 A.2.5 signature with SHA-256, message = "sample": `r || s`.
+const SAMPLE_SIGNATURE: [u8; 64] = hex!(
+    "EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716"
+    "F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8"
+);
+
+fn signing_key() -> SigningKey {
+    SigningKey::from_bytes(&SECRET_KEY.into()).unwrap()
+}
+
+fn sample_prehash() -> FieldBytes {
+    Sha256::digest(b"sample")
+}
+
+#[test]
+fn rfc6979_sample() {
+    let signing_key = signing_key();
+    let public_key = signing_key.verifying_key().as_affine();
+    assert_eq!(public_key.x().as_slice(), &PUBLIC_KEY.0);
+    assert_eq!(public_key.y().as_slice(), &PUBLIC_KEY.1);
+
+    let signature: Signature = signing_key.sign(b"sample");
+    assert_eq!(signature.to_bytes().as_slice(), &SAMPLE_SIGNATURE);
+    assert!(signing_key
+        .verifying_key()
+        .verify(b"sample", &signature)
+        .is_ok());
+    assert!(signing_key
+        .verifying_key()
+        .verify(b"example", &signature)
+        .is_err());
+}
+
+#[test]
+fn recover_key_from_reused_nonce() {
+    let d = hex!("877E53897059500C7BB6BCCBF6433F3393F4C01368DA448F421B0686DCCA2DA2");
+    let k = hex!("76C575F357E9DC8E9CEAC59C17799CA37FF3B1DDCCA3EA1C897D4050B3EF8CE1");
+
+    // SHA-256("message one") and SHA-256("message two")
+    let z1 = hex!("EFA9B12B63BA178B566B29353F9C402B6BA8F0280FE7139E657D5244F93C98A5");
+    let z2 = hex!("1699A8C4E2D8C3C5E942F9C9EEF3F39E3C2C870217FC5A345A07EA7F4C17DB77");
+
+    // 𝒓 = (𝑘×𝑮).x mod 𝒏, 𝒔ᵢ = 𝑘⁻¹(𝒛ᵢ + 𝒓𝒅) mod 𝒏, computed independently
+    let r = hex!("060B9D8EB82F0F6E125F9EE739358C546A34B485F119BADDB202B1BE82A63AE8");
+    let sig1 = Signature::from_scalars(
+        r,
+        hex!("489D094F03BDF92AF93B33685102D889A4C40514EE97181559A86144F240693B"),
+    )
+    .unwrap();
+    let sig2 = Signature::from_scalars(
+        r,
+        hex!("76167EFD9067E9F2FA34613AE9FABAAA775B074DC8A7C3262C9737397ED04E1C"),
+    )
+    .unwrap();
+
+    // Sanity check the vectors against the ECDSA signing equation
+    let d_scalar = NonZeroScalar::from_repr(d.into()).unwrap();
+    let k_scalar = NonZeroScalar::from_repr(k.into()).unwrap();
+    let (sig, _) = hazmat::sign_prehashed::<P256>(&d_scalar, &k_scalar, &z1.into()).unwrap();
+    assert_eq!(sig, sig1);
+    let (sig, _) = hazmat::sign_prehashed::<P256>(&d_scalar, &k_scalar, &z2.into()).unwrap();
+    assert_eq!(sig, sig2);
+
+    let recovered =
+        ecdsa::recover_key_from_reused_nonce(&sig1, &z1.into(), &sig2, &z2.into()).unwrap();
+    assert_eq!(recovered.to_repr().as_slice(), &d);
+
+    // Same message twice: `k` can't be solved for
+    assert!(ecdsa::recover_key_from_reused_nonce(&sig1, &z1.into(), &sig1, &z1.into()).is_none());
+}
+
+#[test]
+fn verify_with_point() {
+    let signing_key = signing_key();
+    let q = ProjectivePoint::from(*signing_key.verifying_key().as_affine());
+    let (big_r, _) = hazmat::ephemeral_point(&NonZeroScalar::from_repr(SAMPLE_K.into()).unwrap());
+    let signature = Signature::from_slice(&SAMPLE_SIGNATURE).unwrap();
+    let z = sample_prehash();
+
+    assert!(hazmat::verify_with_point::<P256>(&q, &z, &big_r, &signature.s()).is_ok());
+
+    // -R has the same x-coordinate (and so the same `r`), but isn't `R`
+    let neg_r = (-ProjectivePoint::from(big_r)).into();
+    assert!(hazmat::verify_with_point::<P256>(&q, &z, &neg_r, &signature.s()).is_err());
+}
+
+#[test]
+fn sign_scalar_rfc6979() {
+    let d = NonZeroScalar::from_repr(SECRET_KEY.into()).unwrap();
+    let z = <p256::Scalar as Reduce<U256>>::reduce_bytes(&sample_prehash());
+    let (signature, _) = hazmat::sign_scalar_rfc6979::<P256, Sha256>(&d, &z, &[]).unwrap();
+    assert_eq!(signature.to_bytes().as_slice(), &SAMPLE_SIGNATURE);
+}
+
+#[test]
+fn ephemeral_point() {
+    let k = NonZeroScalar::from_repr(SAMPLE_K.into()).unwrap();
+    let (big_r, r) = hazmat::ephemeral_point(&k);
+    assert_eq!(
+        ProjectivePoint::from(big_r),
+        ProjectivePoint::GENERATOR * *k
+    );
+    assert_eq!(r.to_repr().as_slice(), &SAMPLE_SIGNATURE[..32]);
+}
+
+#[test]
+fn verify_prehash_with_sinv() {
+    let verifying_key = *signing_key().verifying_key();
+    let signature = Signature::from_slice(&SAMPLE_SIGNATURE).unwrap();
+    let s_inv = *signature.s().invert();
+    let prehash = sample_prehash();
+
+    assert!(verifying_key
+        .verify_prehash_with_sinv(&prehash, &signature, &s_inv)
+        .is_ok());
+
+    // `s_inv` which isn't the inverse of `s`
+    assert!(verifying_key
+        .verify_prehash_with_sinv(&prehash, &signature, &signature.s())
+        .is_err());
+
+    // Different message
+    let prehash = Sha256::digest(b"example");
+    assert!(verifying_key
+        .verify_prehash_with_sinv(&prehash, &signature, &s_inv)
+        .is_err());
+}
+
+#[test]
+fn verify_field_prehash() {
+    let verifying_key = *signing_key().verifying_key();
+    let signature = Signature::from_slice(&SAMPLE_SIGNATURE).unwrap();
+
+    let z = hazmat::bits2field::<P256>(&sample_prehash()).unwrap();
+    assert!(verifying_key.verify_field_prehash(&z, &signature).is_ok());
+
+    let z = hazmat::bits2field::<P256>(&Sha256::digest(b"example")).unwrap();
+    assert!(verifying_key.verify_field_prehash(&z, &signature).is_err());
+}
+
+#[test]
+fn verify_any_message() {
+    let verifying_key = *signing_key().verifying_key();
+    let signature = Signature::from_slice(&SAMPLE_SIGNATURE).unwrap();
+
+    assert_eq!(
+        verifying_key.verify_any_message(&[b"example", b"sample", b"test"], &signature),
+        Some(1)
+    );
+    assert_eq!(
+        verifying_key.verify_any_message(&[b"example", b"test", b"Sample"], &signature),
+        None
+    );
+    assert_eq!(verifying_key.verify_any_message(&[], &signature), None);
+}
+
+#[test]
+fn verify_with_digest_instance() {
+    let verifying_key = *signing_key().verifying_key();
+    let signature = Signature::from_slice(&SAMPLE_SIGNATURE).unwrap();
+
+    let digest = Sha256::new().chain_update(b"sam").chain_update(b"ple");
+    assert!(verifying_key
+        .verify_with_digest_instance(digest, &signature)
+        .is_ok());
+
+    let digest = Sha256::new().chain_update(b"exam").chain_update(b"ple");
+    assert!(verifying_key
+        .verify_with_digest_instance(digest, &signature)
+        .is_err());
+}
+
+#[cfg(feature = "der")]
+#[test]
+fn sign_prehash_both() {
+    let (signature, der_signature) = signing_key().sign_prehash_both(&sample_prehash()).unwrap();
+    assert_eq!(signature.to_bytes().as_slice(), &SAMPLE_SIGNATURE);
+    assert_eq!(der_signature.as_bytes(), signature.to_der().as_bytes());
+    assert_eq!(
+        Signature::from_der(der_signature.as_bytes()).unwrap(),
+        signature
+    );
+}
+
+#[test]
+fn candidate_points_from_r() {
+    let k = NonZeroScalar::from_repr(SAMPLE_K.into()).unwrap();
+    let (big_r, _) = hazmat::ephemeral_point(&k);
+    let r = FieldBytes::try_from(&SAMPLE_SIGNATURE[..32]).unwrap();
+
+    // `r + n` overflows the base field, so only `R` and `-R` are candidates
+    let candidates = ecdsa::candidate_points_from_r::<P256>(&r).collect::<Vec<_>>();
+    assert_eq!(candidates.len(), 2);
+    assert!(candidates.iter().all(|(_, point)| point.x() == r));
+    assert_ne!(candidates[0].1, candidates[1].1);
+
+    let (_, recovery_id) = signing_key()
+        .sign_prehash_recoverable(&sample_prehash())
+        .unwrap();
+    let (_, point) = candidates
+        .iter()
+        .find(|(id, _)| *id == recovery_id)
+        .unwrap();
+    assert_eq!(*point, big_r);
+}
+
+/// Deterministic RNG which remembers the last output of `fill_bytes`.
+struct TestRng {
+    counter: u64,
+    last: FieldBytes,
+}
+
+impl RngCore for TestRng {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(32) {
+            self.counter += 1;
+            let output = Sha256::digest(self.counter.to_be_bytes());
+            chunk.copy_from_slice(&output[..chunk.len()]);
+        }
+
+        if let Ok(bytes) = FieldBytes::try_from(&*dest) {
+            self.last = bytes;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for TestRng {}
+
+#[test]
+fn sign_prehash_recoverable_with_parity() {
+    fn check<const NORMALIZE_S: bool>() -> usize {
+        let signing_key =
+            ecdsa::SigningKey::<P256<NORMALIZE_S>>::from_bytes(&SECRET_KEY.into()).unwrap();
+        let prehash = sample_prehash();
+        let mut rng = TestRng {
+            counter: 0,
+            last: FieldBytes::default(),
+        };
+        let mut flipped = 0;
+
+        for want_even_y in [true, false].into_iter().cycle().take(32) {
+            let (signature, recovery_id) = signing_key
+                .sign_prehash_recoverable_with_parity(&mut rng, &prehash, want_even_y)
+                .unwrap();
+            assert_eq!(recovery_id.is_y_odd(), !want_even_y);
+
+            // The recovery ID must select the `R` which matches the returned `s`
+            let recovered =
+                ecdsa::VerifyingKey::recover_from_prehash(&prehash, &signature, recovery_id)
+                    .unwrap();
+            assert_eq!(&recovered, signing_key.verifying_key());
+
+            // Redo the successful attempt without low-S normalization to see
+            // whether normalization flipped the parity of `R`
+            let (unnormalized, unnormalized_id) = hazmat::sign_prehashed_rfc6979::<P256, Sha256>(
+                &NonZeroScalar::from_repr(SECRET_KEY.into()).unwrap(),
+                &prehash,
+                &rng.last,
+            )
+            .unwrap();
+
+            if NORMALIZE_S && bool::from(unnormalized.s().is_high()) {
+                // Negating `s` also negates `R`, flipping its parity
+                assert_eq!(unnormalized_id.is_y_odd(), want_even_y);
+                flipped += 1;
+            } else {
+                assert_eq!(unnormalized.to_bytes(), signature.to_bytes());
+                assert_eq!(unnormalized_id, recovery_id);
+            }
+        }
+
+        flipped
+    }
+
+    check::<false>();
+
+    // Make sure the case where normalization flips the parity was exercised
+    assert!(check::<true>() > 0);
+}
+
+#[cfg(feature = "sha2")]
+#[test]
+fn verify_with_oid() {
+    use ecdsa::{SignatureWithOid, ECDSA_SHA256_OID, ECDSA_SHA384_OID};
+
+    let verifying_key = *signing_key().verifying_key();
+
+    // RFC6979 A.2.5 signature with SHA-384, message = "sample"
+    let sha384_signature = Signature::from_slice(&hex!(
+        "0EAFEA039B20E9B42309FB1D89E213057CBF973DC0CFC8F129EDDDC800EF7719"
+        "4861F0491E6998B9455193E34E7B0D284DDD7149A74B95B9261F13ABDE940954"
+    ))
+    .unwrap();
+    let sha256_signature = Signature::from_slice(&SAMPLE_SIGNATURE).unwrap();
+
+    for (signature, oid, wrong_oid) in [
+        (sha256_signature, ECDSA_SHA256_OID, ECDSA_SHA384_OID),
+        (sha384_signature, ECDSA_SHA384_OID, ECDSA_SHA256_OID),
+    ] {
+        let signature_with_oid = SignatureWithOid::new(signature, oid).unwrap();
+        assert!(verifying_key
+            .verify_with_oid(b"sample", &signature_with_oid)
+            .is_ok());
+        assert!(verifying_key
+            .verify_with_oid(b"example", &signature_with_oid)
+            .is_err());
+
+        // Signature computed over a different digest than the OID says
+        let signature_with_oid = SignatureWithOid::new(signature, wrong_oid).unwrap();
+        assert!(verifying_key
+            .verify_with_oid(b"sample", &signature_with_oid)
+            .is_err());
+    }
+}
+
+#[test]
+fn domain_separation() {
+    let signing_key = signing_key();
+    let verifying_key = signing_key.verifying_key();
+
+    let signature: Signature = signing_key.domain_separated(b"tag A").sign(b"sample");
+    assert!(verifying_key
+        .domain_separated(b"tag A")
+        .verify(b"sample", &signature)
+        .is_ok());
+
+    // Signatures don't verify under another tag, or without one
+    assert!(verifying_key
+        .domain_separated(b"tag B")
+        .verify(b"sample", &signature)
+        .is_err());
+    assert!(verifying_key.verify(b"sample", &signature).is_err());
+    assert!(verifying_key
+        .domain_separated(b"tag A")
+        .verify(
+            b"sample",
+            &Signature::from_slice(&SAMPLE_SIGNATURE).unwrap()
+        )
+        .is_err());
+}
+
+#[test]
+fn verify_batch() {
+    let signing_keys = (1..=10u8)
+        .map(|i| SigningKey::from_bytes(&[i; 32].into()).unwrap())
+        .collect::<Vec<_>>();
+    let messages = (0..100u8).map(|i| [i; 16]).collect::<Vec<_>>();
+    let mut signatures = messages
+        .iter()
+        .enumerate()
+        .map(|(i, msg)| signing_keys[i % 10].sign(msg))
+        .collect::<Vec<Signature>>();
+
+    let batch = |signatures: &[Signature]| {
+        let items = messages
+            .iter()
+            .zip(signatures)
+            .enumerate()
+            .map(|(i, (msg, signature))| {
+                (*signing_keys[i % 10].verifying_key(), &msg[..], signature)
+            })
+            .collect::<Vec<_>>();
+        ecdsa::VerifyingKey::verify_batch(&items)
+    };
+
+    assert_eq!(batch(&signatures), Ok(()));
+
+    // Corrupt a single signature by swapping in another item's `s`
+    signatures[42] = Signature::from_scalars(signatures[42].r(), signatures[43].s()).unwrap();
+    assert_eq!(batch(&signatures), Err(42));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn sign_prehash_batch() {
+    use ecdsa::signature::hazmat::PrehashSigner;
+
+    let signing_key = signing_key();
+    let prehashes = [
+        Sha256::digest(b"sample").to_vec(),
+        Sha256::digest(b"test").to_vec(),
+        sha2::Sha384::digest(b"sample").to_vec(),
+    ];
+    let prehashes = prehashes.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+    let signatures = signing_key.sign_prehash_batch(&prehashes).unwrap();
+    assert_eq!(signatures.len(), prehashes.len());
+    assert_eq!(signatures[0].to_bytes().as_slice(), &SAMPLE_SIGNATURE);
+
+    for (prehash, signature) in prehashes.iter().zip(&signatures) {
+        let expected: Signature = signing_key.sign_prehash(prehash).unwrap();
+        assert_eq!(signature, &expected);
+    }
+
+    assert!(signing_key.sign_prehash_batch(&[&[0u8; 8]]).is_err());
+}
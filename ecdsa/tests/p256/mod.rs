@@ -0,0 +1,853 @@
+//! NIST P-256 with a simple arithmetic backend, for tests which need to
+//! actually compute signatures (unlike `MockCurve`).
+//!
+//! Field elements and scalars use `crypto-bigint`'s Montgomery form, and
+//! points use the complete formulas for `a = -3` from
+//! [Renes-Costello-Batina 2015] (Algorithms 4 and 6).
+//!
+//! This implementation favors simplicity over performance and makes no
+//! claims about running in constant time. DO NOT USE OUTSIDE OF TESTS!
+//!
+//! [Renes-Costello-Batina 2015]: https://eprint.iacr.org/2015/1060
+
+#![allow(dead_code)]
+
+use core::{
+    cmp::Ordering,
+    fmt::Debug,
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use ecdsa::{hazmat::DigestPrimitive, EcdsaCurve};
+use elliptic_curve::{
+    array::typenum::U32,
+    bigint::{
+        impl_modulus,
+        modular::{ConstMontyForm, ConstMontyParams},
+        ArrayEncoding, Integer, U256,
+    },
+    ff::{self, Field, PrimeField},
+    group,
+    ops::{Invert, LinearCombination, MulByGenerator, Reduce, ShrAssign},
+    point::{AffineCoordinates, DecompressPoint},
+    rand_core::RngCore,
+    scalar::{FromUintUnchecked, IsHigh},
+    sec1::{Coordinates, EncodedPoint, FromEncodedPoint, ToEncodedPoint},
+    subtle::{
+        Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+        CtOption,
+    },
+    zeroize::DefaultIsZeroes,
+    Curve, CurveArithmetic, FieldBytesEncoding, PrimeCurve, ScalarPrimitive,
+};
+
+/// Number of limbs in a [`U256`] on the current target.
+const LIMBS: usize = U256::LIMBS;
+
+/// Serialized field element or scalar.
+pub type FieldBytes = elliptic_curve::FieldBytes<P256>;
+
+/// Element of the base field.
+pub type FieldElement = Elem<FieldModulus>;
+
+/// Element of the scalar field.
+pub type Scalar = Elem<ScalarModulus>;
+
+/// NIST P-256 curve.
+///
+/// `NORMALIZE_S` selects whether ECDSA signatures are low-S normalized, which
+/// is the case for e.g. secp256k1 but not for P-256 itself.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct P256<const NORMALIZE_S: bool = false>;
+
+impl<const NORMALIZE_S: bool> Curve for P256<NORMALIZE_S> {
+    type FieldBytesSize = U32;
+    type Uint = U256;
+
+    const ORDER: U256 =
+        U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+}
+
+impl<const NORMALIZE_S: bool> PrimeCurve for P256<NORMALIZE_S> {}
+
+impl<const NORMALIZE_S: bool> CurveArithmetic for P256<NORMALIZE_S> {
+    type AffinePoint = AffinePoint;
+    type ProjectivePoint = ProjectivePoint;
+    type Scalar = Scalar;
+}
+
+impl<const NORMALIZE_S: bool> EcdsaCurve for P256<NORMALIZE_S> {
+    const NORMALIZE_S: bool = NORMALIZE_S;
+    const CONSTANT_TIME_INVERT: bool = false;
+}
+
+impl<const NORMALIZE_S: bool> DigestPrimitive for P256<NORMALIZE_S> {
+    type Digest = sha2::Sha256;
+}
+
+impl<const NORMALIZE_S: bool> FieldBytesEncoding<P256<NORMALIZE_S>> for U256 {}
+
+impl_modulus!(
+    FieldModulus,
+    U256,
+    "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
+);
+
+impl_modulus!(
+    ScalarModulus,
+    U256,
+    "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+);
+
+/// Prime field modulus along with the constants needed by [`PrimeField`].
+pub trait Modulus: ConstMontyParams<LIMBS> + Copy + Debug + Default + Eq + Send + Sync {
+    /// Modulus as a `0x`-prefixed hex string.
+    const HEX: &'static str;
+
+    /// Multiplicative generator of the field.
+    const GENERATOR: u64;
+
+    /// 2-adicity of `modulus - 1`, i.e. `S` such that `modulus - 1 = t * 2^S`.
+    const TWO_ADICITY: u32;
+
+    /// `GENERATOR^t`.
+    const ROOT_OF_UNITY: U256;
+
+    /// Inverse of [`Modulus::ROOT_OF_UNITY`].
+    const ROOT_OF_UNITY_INV: U256;
+
+    /// `GENERATOR^(2^S)`.
+    const DELTA: U256;
+
+    /// `1/2`.
+    const TWO_INV: U256;
+
+    /// `(t - 1) / 2` as little endian 64-bit words, for Tonelli-Shanks.
+    const T_MINUS_ONE_DIV_TWO: [u64; 4];
+}
+
+impl Modulus for FieldModulus {
+    const HEX: &'static str = "0xffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+    const GENERATOR: u64 = 6;
+    const TWO_ADICITY: u32 = 1;
+    const ROOT_OF_UNITY: U256 =
+        U256::from_be_hex("ffffffff00000001000000000000000000000000fffffffffffffffffffffffe");
+    const ROOT_OF_UNITY_INV: U256 = Self::ROOT_OF_UNITY;
+    const DELTA: U256 = U256::from_u64(36);
+    const TWO_INV: U256 =
+        U256::from_be_hex("7fffffff80000000800000000000000000000000800000000000000000000000");
+    const T_MINUS_ONE_DIV_TWO: [u64; 4] = [
+        0xffffffffffffffff,
+        0x000000003fffffff,
+        0x4000000000000000,
+        0x3fffffffc0000000,
+    ];
+}
+
+impl Modulus for ScalarModulus {
+    const HEX: &'static str = "0xffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+    const GENERATOR: u64 = 7;
+    const TWO_ADICITY: u32 = 4;
+    const ROOT_OF_UNITY: U256 =
+        U256::from_be_hex("ffc97f062a770992ba807ace842a3dfc1546cad004378daf0592d7fbb41e6602");
+    const ROOT_OF_UNITY_INV: U256 =
+        U256::from_be_hex("a0a66a5562d46f2ac645fa0458131caee3ac117c794c4137379c7f0657c73764");
+    const DELTA: U256 = U256::from_u64(33232930569601);
+    const TWO_INV: U256 =
+        U256::from_be_hex("7fffffff800000007fffffffffffffffde737d56d38bcf4279dce5617e3192a9");
+    const T_MINUS_ONE_DIV_TWO: [u64; 4] = [
+        0x279dce5617e3192a,
+        0xfde737d56d38bcf4,
+        0x07ffffffffffffff,
+        0x07fffffff8000000,
+    ];
+}
+
+/// Element of the prime field defined by `M`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Elem<M: Modulus>(ConstMontyForm<M, LIMBS>);
+
+impl<M: Modulus> Elem<M> {
+    /// Create an element from an integer which MUST be less than the modulus.
+    const fn from_uint_reduced(uint: &U256) -> Self {
+        Self(ConstMontyForm::new(uint))
+    }
+
+    /// Create an element from an integer, checking that it's in range.
+    pub fn from_uint(uint: U256) -> CtOption<Self> {
+        let in_range = uint.ct_lt(M::MODULUS.as_ref());
+        CtOption::new(Self::from_uint_reduced(&uint), in_range)
+    }
+
+    /// Create an element from a big endian hex string, panicking if it's out of range.
+    pub fn from_hex(hex: &str) -> Self {
+        Self::from_uint(U256::from_be_hex(hex)).unwrap()
+    }
+
+    /// Canonical integer representation of this element.
+    pub const fn to_uint(self) -> U256 {
+        self.0.retrieve()
+    }
+}
+
+impl<M: Modulus> Field for Elem<M> {
+    const ZERO: Self = Self(ConstMontyForm::ZERO);
+    const ONE: Self = Self(ConstMontyForm::ONE);
+
+    fn random(mut rng: impl RngCore) -> Self {
+        let mut bytes = FieldBytes::default();
+
+        loop {
+            rng.fill_bytes(&mut bytes);
+            if let Some(elem) = Self::from_repr(bytes).into() {
+                return elem;
+            }
+        }
+    }
+
+    fn square(&self) -> Self {
+        Self(self.0.square())
+    }
+
+    fn double(&self) -> Self {
+        *self + self
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        // Fermat's little theorem: `x^(m - 2) = x^-1`
+        let exp = M::MODULUS.as_ref().wrapping_sub(&U256::from_u8(2));
+        CtOption::new(Self(self.0.pow(&exp)), !self.is_zero())
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        ff::helpers::sqrt_tonelli_shanks(self, M::T_MINUS_ONE_DIV_TWO)
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        ff::helpers::sqrt_ratio_generic(num, div)
+    }
+}
+
+impl<M: Modulus> PrimeField for Elem<M> {
+    type Repr = FieldBytes;
+
+    const MODULUS: &'static str = M::HEX;
+    const NUM_BITS: u32 = 256;
+    const CAPACITY: u32 = 255;
+    const TWO_INV: Self = Self::from_uint_reduced(&M::TWO_INV);
+    const MULTIPLICATIVE_GENERATOR: Self = Self::from_uint_reduced(&U256::from_u64(M::GENERATOR));
+    const S: u32 = M::TWO_ADICITY;
+    const ROOT_OF_UNITY: Self = Self::from_uint_reduced(&M::ROOT_OF_UNITY);
+    const ROOT_OF_UNITY_INV: Self = Self::from_uint_reduced(&M::ROOT_OF_UNITY_INV);
+    const DELTA: Self = Self::from_uint_reduced(&M::DELTA);
+
+    fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
+        Self::from_uint(U256::from_be_byte_array(bytes))
+    }
+
+    fn to_repr(&self) -> FieldBytes {
+        self.to_uint().to_be_byte_array()
+    }
+
+    fn is_odd(&self) -> Choice {
+        self.to_uint().is_odd()
+    }
+}
+
+impl<M: Modulus> AsRef<Elem<M>> for Elem<M> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl<M: Modulus> ConditionallySelectable for Elem<M> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(ConstMontyForm::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl<M: Modulus> ConstantTimeEq for Elem<M> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<M: Modulus> DefaultIsZeroes for Elem<M> {}
+
+impl<M: Modulus> PartialOrd for Elem<M> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<M: Modulus> Ord for Elem<M> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_uint().cmp(&other.to_uint())
+    }
+}
+
+macro_rules! impl_elem_op {
+    ($op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident) => {
+        impl<M: Modulus> $op for Elem<M> {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: Self) -> Self {
+                Self(ConstMontyForm::$op_fn(&self.0, &rhs.0))
+            }
+        }
+
+        impl<M: Modulus> $op<&Elem<M>> for Elem<M> {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: &Self) -> Self {
+                Self(ConstMontyForm::$op_fn(&self.0, &rhs.0))
+            }
+        }
+
+        impl<M: Modulus> $op_assign for Elem<M> {
+            fn $op_assign_fn(&mut self, rhs: Self) {
+                *self = $op::$op_fn(*self, rhs);
+            }
+        }
+
+        impl<M: Modulus> $op_assign<&Elem<M>> for Elem<M> {
+            fn $op_assign_fn(&mut self, rhs: &Self) {
+                *self = $op::$op_fn(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_elem_op!(Add, add, AddAssign, add_assign);
+impl_elem_op!(Sub, sub, SubAssign, sub_assign);
+impl_elem_op!(Mul, mul, MulAssign, mul_assign);
+
+impl<M: Modulus> Neg for Elem<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(ConstMontyForm::neg(&self.0))
+    }
+}
+
+impl<M: Modulus> Sum for Elem<M> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a, M: Modulus> Sum<&'a Elem<M>> for Elem<M> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<M: Modulus> Product for Elem<M> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
+    }
+}
+
+impl<'a, M: Modulus> Product<&'a Elem<M>> for Elem<M> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
+    }
+}
+
+impl<M: Modulus> Invert for Elem<M> {
+    type Output = CtOption<Self>;
+
+    fn invert(&self) -> CtOption<Self> {
+        Field::invert(self)
+    }
+}
+
+impl<M: Modulus> IsHigh for Elem<M> {
+    fn is_high(&self) -> Choice {
+        let half = M::MODULUS.as_ref().shr_vartime(1);
+        self.to_uint().ct_gt(&half)
+    }
+}
+
+impl<M: Modulus> Reduce<U256> for Elem<M> {
+    type Bytes = FieldBytes;
+
+    fn reduce(n: U256) -> Self {
+        // The moduli are both greater than 2^255, so one subtraction suffices
+        let modulus = M::MODULUS.as_ref();
+        let reduced = U256::conditional_select(&n, &n.wrapping_sub(modulus), !n.ct_lt(modulus));
+        Self::from_uint_reduced(&reduced)
+    }
+
+    fn reduce_bytes(bytes: &FieldBytes) -> Self {
+        Self::reduce(U256::from_be_byte_array(*bytes))
+    }
+}
+
+impl<M: Modulus> ShrAssign<usize> for Elem<M> {
+    fn shr_assign(&mut self, rhs: usize) {
+        *self = Self::from_uint_reduced(&self.to_uint().shr_vartime(rhs as u32));
+    }
+}
+
+impl<M: Modulus> FromUintUnchecked for Elem<M> {
+    type Uint = U256;
+
+    fn from_uint_unchecked(uint: U256) -> Self {
+        Self::from_uint_reduced(&uint)
+    }
+}
+
+impl<M: Modulus> From<u64> for Elem<M> {
+    fn from(n: u64) -> Self {
+        Self::from_uint_reduced(&U256::from_u64(n))
+    }
+}
+
+impl<M: Modulus> From<Elem<M>> for U256 {
+    fn from(elem: Elem<M>) -> U256 {
+        elem.to_uint()
+    }
+}
+
+impl<M: Modulus> From<Elem<M>> for FieldBytes {
+    fn from(elem: Elem<M>) -> FieldBytes {
+        elem.to_repr()
+    }
+}
+
+impl<const NORMALIZE_S: bool> From<ScalarPrimitive<P256<NORMALIZE_S>>> for Scalar {
+    fn from(scalar: ScalarPrimitive<P256<NORMALIZE_S>>) -> Scalar {
+        Self::from_uint_reduced(scalar.as_uint())
+    }
+}
+
+impl<const NORMALIZE_S: bool> From<Scalar> for ScalarPrimitive<P256<NORMALIZE_S>> {
+    fn from(scalar: Scalar) -> Self {
+        ScalarPrimitive::new(scalar.to_uint()).unwrap()
+    }
+}
+
+/// Coefficient `b` of the curve equation `y² = x³ - 3x + b`.
+const CURVE_B: FieldElement = FieldElement::from_uint_reduced(&U256::from_be_hex(
+    "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+));
+
+/// Compute `x³ - 3x + b`, i.e. the right hand side of the curve equation.
+fn curve_rhs(x: &FieldElement) -> FieldElement {
+    x.square() * x - x.double() - x + CURVE_B
+}
+
+/// Point on the curve in affine coordinates.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AffinePoint {
+    x: FieldElement,
+    y: FieldElement,
+    infinity: u8,
+}
+
+impl AffinePoint {
+    /// Additive identity, a.k.a. the point at infinity.
+    pub const IDENTITY: Self = Self {
+        x: FieldElement::ZERO,
+        y: FieldElement::ZERO,
+        infinity: 1,
+    };
+
+    /// Base point of P-256.
+    pub const GENERATOR: Self = Self {
+        x: FieldElement::from_uint_reduced(&U256::from_be_hex(
+            "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+        )),
+        y: FieldElement::from_uint_reduced(&U256::from_be_hex(
+            "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+        )),
+        infinity: 0,
+    };
+
+    /// Is this point the identity?
+    pub fn is_identity(&self) -> Choice {
+        Choice::from(self.infinity)
+    }
+
+    /// Affine `y` coordinate.
+    pub fn y(&self) -> FieldBytes {
+        self.y.to_repr()
+    }
+}
+
+impl AffineCoordinates for AffinePoint {
+    type FieldRepr = FieldBytes;
+
+    fn x(&self) -> FieldBytes {
+        self.x.to_repr()
+    }
+
+    fn y_is_odd(&self) -> Choice {
+        self.y.is_odd()
+    }
+}
+
+impl ConditionallySelectable for AffinePoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: FieldElement::conditional_select(&a.x, &b.x, choice),
+            y: FieldElement::conditional_select(&a.y, &b.y, choice),
+            infinity: u8::conditional_select(&a.infinity, &b.infinity, choice),
+        }
+    }
+}
+
+impl ConstantTimeEq for AffinePoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y) & self.infinity.ct_eq(&other.infinity)
+    }
+}
+
+impl Default for AffinePoint {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl DefaultIsZeroes for AffinePoint {}
+
+impl<const NORMALIZE_S: bool> DecompressPoint<P256<NORMALIZE_S>> for AffinePoint {
+    fn decompress(x_bytes: &FieldBytes, y_is_odd: Choice) -> CtOption<Self> {
+        FieldElement::from_repr(*x_bytes).and_then(|x| {
+            curve_rhs(&x).sqrt().map(|beta| {
+                let y =
+                    FieldElement::conditional_select(&-beta, &beta, beta.is_odd().ct_eq(&y_is_odd));
+
+                Self { x, y, infinity: 0 }
+            })
+        })
+    }
+}
+
+impl<const NORMALIZE_S: bool> FromEncodedPoint<P256<NORMALIZE_S>> for AffinePoint {
+    fn from_encoded_point(point: &EncodedPoint<P256<NORMALIZE_S>>) -> CtOption<Self> {
+        match point.coordinates() {
+            Coordinates::Identity => CtOption::new(Self::IDENTITY, Choice::from(1)),
+            Coordinates::Compact { .. } => CtOption::new(Self::IDENTITY, Choice::from(0)),
+            Coordinates::Compressed { x, y_is_odd } => <Self as DecompressPoint<
+                P256<NORMALIZE_S>,
+            >>::decompress(
+                x, Choice::from(u8::from(y_is_odd))
+            ),
+            Coordinates::Uncompressed { x, y } => FieldElement::from_repr(*x).and_then(|x| {
+                FieldElement::from_repr(*y).and_then(|y| {
+                    let on_curve = y.square().ct_eq(&curve_rhs(&x));
+                    CtOption::new(Self { x, y, infinity: 0 }, on_curve)
+                })
+            }),
+        }
+    }
+}
+
+impl<const NORMALIZE_S: bool> ToEncodedPoint<P256<NORMALIZE_S>> for AffinePoint {
+    fn to_encoded_point(&self, compress: bool) -> EncodedPoint<P256<NORMALIZE_S>> {
+        if self.is_identity().into() {
+            EncodedPoint::<P256<NORMALIZE_S>>::identity()
+        } else {
+            EncodedPoint::<P256<NORMALIZE_S>>::from_affine_coordinates(
+                &self.x.to_repr(),
+                &self.y.to_repr(),
+                compress,
+            )
+        }
+    }
+}
+
+impl From<ProjectivePoint> for AffinePoint {
+    fn from(point: ProjectivePoint) -> AffinePoint {
+        group::Curve::to_affine(&point)
+    }
+}
+
+/// Point on the curve in homogeneous projective coordinates.
+#[derive(Clone, Copy, Debug)]
+pub struct ProjectivePoint {
+    x: FieldElement,
+    y: FieldElement,
+    z: FieldElement,
+}
+
+impl ProjectivePoint {
+    /// Additive identity, a.k.a. the point at infinity.
+    pub const IDENTITY: Self = Self {
+        x: FieldElement::ZERO,
+        y: FieldElement::ONE,
+        z: FieldElement::ZERO,
+    };
+
+    /// Base point of P-256.
+    pub const GENERATOR: Self = Self {
+        x: AffinePoint::GENERATOR.x,
+        y: AffinePoint::GENERATOR.y,
+        z: FieldElement::ONE,
+    };
+
+    /// Complete point addition (Renes-Costello-Batina Algorithm 4).
+    fn add(&self, other: &Self) -> Self {
+        let xx = self.x * other.x;
+        let yy = self.y * other.y;
+        let zz = self.z * other.z;
+        let xy_pairs = ((self.x + self.y) * (other.x + other.y)) - (xx + yy);
+        let yz_pairs = ((self.y + self.z) * (other.y + other.z)) - (yy + zz);
+        let xz_pairs = ((self.x + self.z) * (other.x + other.z)) - (xx + zz);
+
+        let bzz_part = xz_pairs - (CURVE_B * zz);
+        let bzz3_part = bzz_part.double() + bzz_part;
+        let yy_m_bzz3 = yy - bzz3_part;
+        let yy_p_bzz3 = yy + bzz3_part;
+
+        let zz3 = zz.double() + zz;
+        let bxz_part = (CURVE_B * xz_pairs) - (zz3 + xx);
+        let bxz3_part = bxz_part.double() + bxz_part;
+        let xx3_m_zz3 = xx.double() + xx - zz3;
+
+        Self {
+            x: (yy_p_bzz3 * xy_pairs) - (yz_pairs * bxz3_part),
+            y: (yy_p_bzz3 * yy_m_bzz3) + (xx3_m_zz3 * bxz3_part),
+            z: (yy_m_bzz3 * yz_pairs) + (xy_pairs * xx3_m_zz3),
+        }
+    }
+
+    /// Exception-free point doubling (Renes-Costello-Batina Algorithm 6).
+    fn double(&self) -> Self {
+        let xx = self.x.square();
+        let yy = self.y.square();
+        let zz = self.z.square();
+        let xy2 = (self.x * self.y).double();
+        let xz2 = (self.x * self.z).double();
+
+        let bzz_part = (CURVE_B * zz) - xz2;
+        let bzz3_part = bzz_part.double() + bzz_part;
+        let yy_m_bzz3 = yy - bzz3_part;
+        let yy_p_bzz3 = yy + bzz3_part;
+        let y_frag = yy_p_bzz3 * yy_m_bzz3;
+        let x_frag = yy_m_bzz3 * xy2;
+
+        let zz3 = zz.double() + zz;
+        let bxz2_part = (CURVE_B * xz2) - (zz3 + xx);
+        let bxz6_part = bxz2_part.double() + bxz2_part;
+        let xx3_m_zz3 = xx.double() + xx - zz3;
+
+        let y = y_frag + (xx3_m_zz3 * bxz6_part);
+        let yz2 = (self.y * self.z).double();
+        let x = x_frag - (bxz6_part * yz2);
+        let z = (yz2 * yy).double().double();
+
+        Self { x, y, z }
+    }
+
+    /// Double-and-add scalar multiplication.
+    fn mul(&self, k: &Scalar) -> Self {
+        let k = k.to_uint();
+        let mut acc = Self::IDENTITY;
+
+        for i in (0..U256::BITS).rev() {
+            acc = acc.double();
+            let sum = acc.add(self);
+            acc.conditional_assign(&sum, Choice::from(u8::from(k.bit_vartime(i))));
+        }
+
+        acc
+    }
+}
+
+impl group::Group for ProjectivePoint {
+    type Scalar = Scalar;
+
+    fn random(rng: impl RngCore) -> Self {
+        Self::GENERATOR * Scalar::random(rng)
+    }
+
+    fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    fn generator() -> Self {
+        Self::GENERATOR
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.z.is_zero()
+    }
+
+    fn double(&self) -> Self {
+        ProjectivePoint::double(self)
+    }
+}
+
+impl group::Curve for ProjectivePoint {
+    type AffineRepr = AffinePoint;
+
+    fn to_affine(&self) -> AffinePoint {
+        Field::invert(&self.z)
+            .map(|z_inv| AffinePoint {
+                x: self.x * z_inv,
+                y: self.y * z_inv,
+                infinity: 0,
+            })
+            .unwrap_or(AffinePoint::IDENTITY)
+    }
+}
+
+impl LinearCombination<[(ProjectivePoint, Scalar)]> for ProjectivePoint {}
+impl<const N: usize> LinearCombination<[(ProjectivePoint, Scalar); N]> for ProjectivePoint {}
+impl MulByGenerator for ProjectivePoint {}
+
+impl ConditionallySelectable for ProjectivePoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: FieldElement::conditional_select(&a.x, &b.x, choice),
+            y: FieldElement::conditional_select(&a.y, &b.y, choice),
+            z: FieldElement::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+impl ConstantTimeEq for ProjectivePoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // (X₁ : Y₁ : Z₁) = (X₂ : Y₂ : Z₂) iff X₁Z₂ = X₂Z₁ and Y₁Z₂ = Y₂Z₁
+        (self.x * other.z).ct_eq(&(other.x * self.z))
+            & (self.y * other.z).ct_eq(&(other.y * self.z))
+    }
+}
+
+impl PartialEq for ProjectivePoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for ProjectivePoint {}
+
+impl Default for ProjectivePoint {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl DefaultIsZeroes for ProjectivePoint {}
+
+impl From<AffinePoint> for ProjectivePoint {
+    fn from(point: AffinePoint) -> ProjectivePoint {
+        let projective = Self {
+            x: point.x,
+            y: point.y,
+            z: FieldElement::ONE,
+        };
+
+        Self::conditional_select(&projective, &Self::IDENTITY, point.is_identity())
+    }
+}
+
+macro_rules! impl_point_op {
+    ($rhs:ty, $op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident, $f:expr) => {
+        impl $op<$rhs> for ProjectivePoint {
+            type Output = ProjectivePoint;
+
+            fn $op_fn(self, rhs: $rhs) -> ProjectivePoint {
+                $f(&self, &ProjectivePoint::from(rhs))
+            }
+        }
+
+        impl $op<&$rhs> for ProjectivePoint {
+            type Output = ProjectivePoint;
+
+            fn $op_fn(self, rhs: &$rhs) -> ProjectivePoint {
+                $f(&self, &ProjectivePoint::from(*rhs))
+            }
+        }
+
+        impl $op_assign<$rhs> for ProjectivePoint {
+            fn $op_assign_fn(&mut self, rhs: $rhs) {
+                *self = $f(self, &ProjectivePoint::from(rhs));
+            }
+        }
+
+        impl $op_assign<&$rhs> for ProjectivePoint {
+            fn $op_assign_fn(&mut self, rhs: &$rhs) {
+                *self = $f(self, &ProjectivePoint::from(*rhs));
+            }
+        }
+    };
+}
+
+fn point_sub(lhs: &ProjectivePoint, rhs: &ProjectivePoint) -> ProjectivePoint {
+    lhs.add(&-*rhs)
+}
+
+impl_point_op!(
+    ProjectivePoint,
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    ProjectivePoint::add
+);
+impl_point_op!(ProjectivePoint, Sub, sub, SubAssign, sub_assign, point_sub);
+impl_point_op!(
+    AffinePoint,
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    ProjectivePoint::add
+);
+impl_point_op!(AffinePoint, Sub, sub, SubAssign, sub_assign, point_sub);
+
+impl Mul<Scalar> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn mul(self, k: Scalar) -> ProjectivePoint {
+        ProjectivePoint::mul(&self, &k)
+    }
+}
+
+impl Mul<&Scalar> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn mul(self, k: &Scalar) -> ProjectivePoint {
+        ProjectivePoint::mul(&self, k)
+    }
+}
+
+impl MulAssign<Scalar> for ProjectivePoint {
+    fn mul_assign(&mut self, k: Scalar) {
+        *self = ProjectivePoint::mul(self, &k);
+    }
+}
+
+impl MulAssign<&Scalar> for ProjectivePoint {
+    fn mul_assign(&mut self, k: &Scalar) {
+        *self = ProjectivePoint::mul(self, k);
+    }
+}
+
+impl Neg for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn neg(self) -> ProjectivePoint {
+        Self {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
+    }
+}
+
+impl Sum for ProjectivePoint {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::IDENTITY, |acc, point| acc.add(&point))
+    }
+}
+
+impl<'a> Sum<&'a ProjectivePoint> for ProjectivePoint {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::IDENTITY, |acc, point| acc.add(point))
+    }
+}
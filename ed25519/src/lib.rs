@@ -283,6 +283,13 @@ use alloc::vec::Vec;
 /// Size of a single component of an Ed25519 signature.
 const COMPONENT_SIZE: usize = 32;
 
+/// Order of the prime-order subgroup `L` (RFC 8032 § 5.1), serialized as a
+/// little endian integer.
+const ORDER: ComponentBytes = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
 /// Size of an `R` or `s` component of an Ed25519 signature when serialized
 /// as bytes.
 pub type ComponentBytes = [u8; COMPONENT_SIZE];
@@ -337,6 +344,41 @@ impl Signature {
             .map_err(|_| Error::new())
     }
 
+    /// Parse an Ed25519 signature from a byte slice, rejecting signatures
+    /// whose `s` component is not canonically encoded, i.e. `s >= L` where `L`
+    /// is the order of the prime-order subgroup as defined in RFC 8032 § 5.1.
+    ///
+    /// The comparison of `s` against `L` runs in constant time.
+    ///
+    /// # Returns
+    /// - `Ok` on success
+    /// - `Err` if the input byte slice is not 64-bytes or `s` is non-canonical
+    pub fn from_bytes_strict(bytes: &[u8]) -> signature::Result<Self> {
+        let signature = Self::from_slice(bytes)?;
+
+        if signature.is_s_canonical() {
+            Ok(signature)
+        } else {
+            Err(Error::new())
+        }
+    }
+
+    /// Is the `s` component of this signature canonically encoded, i.e. is it
+    /// less than the order of the prime-order subgroup `L`?
+    fn is_s_canonical(&self) -> bool {
+        // Compute `s - L` and check whether it underflows, i.e. `s < L`
+        let mut borrow = 0u16;
+
+        for (s_byte, l_byte) in self.s.iter().zip(ORDER.iter()) {
+            let diff = u16::from(*s_byte)
+                .wrapping_sub(u16::from(*l_byte))
+                .wrapping_sub(borrow);
+            borrow = (diff >> 8) & 1;
+        }
+
+        borrow == 1
+    }
+
     /// Bytes for the `R` component of a signature.
     pub fn r_bytes(&self) -> &ComponentBytes {
         &self.R
@@ -347,6 +389,11 @@ impl Signature {
         &self.s
     }
 
+    /// Borrow the `R` and `s` components of a signature.
+    pub fn split(&self) -> (&ComponentBytes, &ComponentBytes) {
+        (&self.R, &self.s)
+    }
+
     /// Return the inner byte array.
     pub fn to_bytes(&self) -> SignatureBytes {
         let mut ret = [0u8; Self::BYTE_SIZE];
@@ -48,3 +48,13 @@ fn from_str_rejects_mixed_case() {
     let result = Signature::from_str("E5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b");
     assert!(result.is_err());
 }
+
+#[test]
+fn display_round_trip() {
+    let sig = Signature::from_bytes(&TEST_1_SIGNATURE);
+
+    for hex in [sig.to_string(), format!("{:x}", sig), format!("{:X}", sig)] {
+        assert_eq!(hex.len(), Signature::BYTE_SIZE * 2);
+        assert_eq!(Signature::from_str(&hex).unwrap(), sig);
+    }
+}
@@ -0,0 +1,66 @@
+//! Signature parsing tests.
+
+use ed25519::{ComponentBytes, Signature};
+use hex_literal::hex;
+
+/// Test 1 signature from RFC 8032 § 7.1
+/// <https://datatracker.ietf.org/doc/html/rfc8032#section-7.1>
+const TEST_1_SIGNATURE: [u8; Signature::BYTE_SIZE] = hex!(
+    "e5564300c360ac729086e2cc806e828a
+     84877f1eb8e5d974d873e06522490155
+     5fb8821590a33bacc61e39701cf9b46b
+     d25bf5f0595bbe24655141438e7a100b"
+);
+
+/// Order of the prime-order subgroup `L`, little endian.
+const ORDER: ComponentBytes =
+    hex!("edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010");
+
+fn with_s(s: ComponentBytes) -> [u8; Signature::BYTE_SIZE] {
+    let mut bytes = TEST_1_SIGNATURE;
+    bytes[32..].copy_from_slice(&s);
+    bytes
+}
+
+#[test]
+fn from_bytes_strict_accepts_canonical_s() {
+    let sig = Signature::from_bytes_strict(&TEST_1_SIGNATURE).unwrap();
+    assert_eq!(sig.to_bytes(), TEST_1_SIGNATURE);
+
+    // `L - 1` is the largest canonical `s`
+    let mut l_minus_one = ORDER;
+    l_minus_one[0] -= 1;
+    assert!(Signature::from_bytes_strict(&with_s(l_minus_one)).is_ok());
+    assert!(Signature::from_bytes_strict(&with_s([0; 32])).is_ok());
+}
+
+#[test]
+fn from_bytes_strict_rejects_non_canonical_s() {
+    let mut l_plus_one = ORDER;
+    l_plus_one[0] += 1;
+
+    for s in [ORDER, l_plus_one, [0xff; 32]] {
+        let bytes = with_s(s);
+        assert!(Signature::from_bytes_strict(&bytes).is_err());
+
+        // The lenient parser still accepts it
+        assert!(Signature::try_from(&bytes[..]).is_ok());
+    }
+}
+
+#[test]
+fn from_bytes_strict_rejects_wrong_length() {
+    assert!(Signature::from_bytes_strict(&TEST_1_SIGNATURE[..63]).is_err());
+    assert!(Signature::from_bytes_strict(&[0; 65]).is_err());
+}
+
+#[test]
+fn components() {
+    let sig = Signature::from_bytes(&TEST_1_SIGNATURE);
+    let (r, s) = sig.split();
+    assert_eq!(r, sig.r_bytes());
+    assert_eq!(s, sig.s_bytes());
+    assert_eq!(&r[..], &TEST_1_SIGNATURE[..32]);
+    assert_eq!(&s[..], &TEST_1_SIGNATURE[32..]);
+    assert_eq!(Signature::from_components(*r, *s), sig);
+}
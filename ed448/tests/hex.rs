@@ -52,3 +52,23 @@ fn from_str_rejects_mixed_case() {
     let result = Signature::from_str("533A37f6bbe457251f023c0d88f976ae2dfb504a843e34d2074fd823d41a591f2b233f034f628281f2fd7a22ddd47d7828c59bd0a21bfd3980ff0d2028d4b18a9df63e006c5d1c2d345b925d8dc00b4104852db99ac5c7cdda8530a113a0f4dbb61149f05a7363268c71d95808ff2e652600");
     assert!(result.is_err());
 }
+
+#[test]
+fn display_round_trip() {
+    let sig = Signature::from_bytes(&TEST_1_SIGNATURE);
+    assert_eq!(sig.to_string().len(), Signature::BYTE_SIZE * 2);
+    assert_eq!(Signature::from_str(&sig.to_string()).unwrap(), sig);
+    assert_eq!(Signature::from_str(&format!("{:x}", sig)).unwrap(), sig);
+}
+
+#[test]
+fn from_str_rejects_invalid_input() {
+    let hex = format!("{:x}", Signature::from_bytes(&TEST_1_SIGNATURE));
+
+    // Odd length
+    assert!(Signature::from_str(&hex[1..]).is_err());
+
+    // Non-hex characters
+    assert!(Signature::from_str(&hex.replacen('5', "g", 1)).is_err());
+    assert!(Signature::from_str(&format!("{}é", &hex[2..])).is_err());
+}
@@ -40,14 +40,18 @@ impl<Mode: LmsMode> SigningKey<Mode> {
         Self::new_from_seed(id, seed).expect("size invariant violation")
     }
 
-    // Returns a new LMS private key generated pseudorandomly from an identifier
-    // and secret seed. The seed must be equal to the hash output length of the
-    // LMS mode ([Mode::M])
+    /// Returns a new LMS private key generated pseudorandomly from an identifier
+    /// and secret seed. The seed must be equal to the hash output length of the
+    /// LMS mode ([`LmsMode::M`]).
+    ///
+    /// Every leaf LM-OTS private key is derived from `id` and `seed` using the
+    /// pseudorandom key generation method from Appendix A of
+    /// <https://datatracker.ietf.org/doc/html/rfc8554#appendix-A>, so the same
+    /// inputs always yield the same Merkle tree and public key.
     pub fn new_from_seed(
         id: Identifier,
         seed: impl AsRef<[u8]>,
     ) -> Result<Self, TryFromSliceError> {
-        //let seed = seed.as_ref();
         let seed = Array::try_from(seed.as_ref())?;
         let mut sk = Self {
             id,
@@ -238,6 +242,13 @@ mod tests {
         assert_eq!(lms_pub.id(), &id);
     }
 
+    #[test]
+    fn test_new_from_seed_wrong_seed_len() {
+        let id = hex!("d08fabd4a2091ff0a8cb4ed834e74534");
+        let seed = [0u8; 31];
+        assert!(SigningKey::<LmsSha256M32H5<LmsOtsSha256N32W8>>::new_from_seed(id, seed).is_err());
+    }
+
     #[test]
     // Byte-for-byte signature equivalence test with RFC 8554 Test Case 2
     // Leaf-level LMS signature. LM_SHA256_M32_H5 / LMOTS_SHA256_N32_W8
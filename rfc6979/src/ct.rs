@@ -63,6 +63,28 @@ mod tests {
     const E: [u8; 4] = [0xFF, 0xFF, 0xFF, 0xFE];
     const F: [u8; 4] = [0xFF, 0xFF, 0xFF, 0xFF];
 
+    #[test]
+    fn ct_leading_zeros() {
+        use super::leading_zeros;
+        assert_eq!(leading_zeros(&B), 8);
+        assert_eq!(leading_zeros(&[0x04, 0x00]), 5);
+        assert_eq!(leading_zeros(&[0x01, 0xFF]), 7);
+        assert_eq!(leading_zeros(&C), 0);
+    }
+
+    #[test]
+    fn ct_rshift() {
+        use super::rshift;
+
+        let mut n = [0xFF, 0xFF, 0xFF, 0xFF];
+        rshift(&mut n, 7);
+        assert_eq!(n, [0x01, 0xFF, 0xFF, 0xFF]);
+
+        let mut n = D;
+        rshift(&mut n, 5);
+        assert_eq!(n, [0x07, 0xF8, 0x00, 0x00]);
+    }
+
     #[test]
     fn ct_is_zero() {
         use super::is_zero;
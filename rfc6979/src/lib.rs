@@ -58,6 +58,9 @@ use hmac::{
 /// - `q`: field modulus
 /// - `h`: hash/digest of input message: must be reduced modulo `q` in advance
 /// - `data`: additional associated data, e.g. CSRNG output used as added entropy
+///
+/// See [`generate_k_mut`] for how moduli whose bit length isn't a multiple
+/// of 8 are handled.
 #[inline]
 pub fn generate_k<D, N>(
     x: &Array<u8, N>,
@@ -85,6 +88,23 @@ where
 /// - `q`: field modulus
 /// - `h`: hash/digest of input message: must be reduced modulo `q` in advance
 /// - `data`: additional associated data, e.g. CSRNG output used as added entropy
+///
+/// # `qlen` handling
+///
+/// All inputs and the output `k` MUST be `rlen = ceil(qlen / 8)` bytes long,
+/// where `qlen` is the bit length of `q`, i.e. `q` MUST NOT begin with a zero
+/// byte.
+///
+/// When `qlen` isn't a multiple of 8 (e.g. K-163, P-521), each candidate
+/// output of `HMAC_DRBG` is converted to an integer using `bits2int` as
+/// described in [RFC6979 § 2.3.2], i.e. it is right shifted by
+/// `8 * rlen - qlen` bits (the number of leading zero bits in `q`) so only
+/// its leftmost `qlen` bits are kept. The candidate is accepted if it lies in
+/// the range `1..q`, otherwise `HMAC_DRBG` is reseeded and a new candidate is
+/// generated as described in [RFC6979 § 3.2] step h.3.
+///
+/// [RFC6979 § 2.3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-2.3.2
+/// [RFC6979 § 3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-3.2
 #[inline]
 pub fn generate_k_mut<D>(x: &[u8], q: &[u8], h: &[u8], data: &[u8], k: &mut [u8])
 where
@@ -96,9 +116,49 @@ where
     assert_eq!(k_len, h.len());
     debug_assert!(bool::from(ct::lt(h, q)));
 
+    generate_k_with_drbg(HmacDrbg::<D>::new(x, h, data), q, k);
+}
+
+/// Deterministically generate ephemeral scalar `k` using [`HmacDrbgPrecomputed`]
+/// state for the secret key `x`.
+///
+/// Produces the same output as [`generate_k`] for the same `x`, but skips
+/// the part of the computation which only depends on `x`, which is useful
+/// when generating `k` for many messages signed with the same key.
+///
+/// Accepts the following parameters and inputs:
+///
+/// - `x`: precomputed state for the secret key, see [`HmacDrbgPrecomputed::new`]
+/// - `q`: field modulus
+/// - `h`: hash/digest of input message: must be reduced modulo `q` in advance
+/// - `data`: additional associated data, e.g. CSRNG output used as added entropy
+#[inline]
+pub fn generate_k_precomputed<D, N>(
+    x: &HmacDrbgPrecomputed<'_, D>,
+    q: &Array<u8, N>,
+    h: &Array<u8, N>,
+    data: &[u8],
+) -> Array<u8, N>
+where
+    D: Digest + BlockSizeUser + FixedOutput + FixedOutputReset + Clone,
+    N: ArraySize,
+{
+    assert_eq!(x.entropy_input.len(), N::USIZE);
+    debug_assert!(bool::from(ct::lt(h, q)));
+
+    let mut k = Array::default();
+    generate_k_with_drbg(x.instantiate(h, data), q, &mut k);
+    k
+}
+
+/// Generate `k` from the output of an instantiated `HMAC_DRBG` as described
+/// in RFC6979 § 3.2 step h.
+fn generate_k_with_drbg<D>(mut hmac_drbg: HmacDrbg<D>, q: &[u8], k: &mut [u8])
+where
+    D: Digest + BlockSizeUser + FixedOutputReset,
+{
     let q_leading_zeros = ct::leading_zeros(q);
     let q_has_leading_zeros = q_leading_zeros != 0;
-    let mut hmac_drbg = HmacDrbg::<D>::new(x, h, data);
 
     loop {
         hmac_drbg.fill_bytes(k);
@@ -136,17 +196,51 @@ where
 {
     /// Initialize `HMAC_DRBG`
     pub fn new(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Self {
-        let mut k = SimpleHmac::new(&Default::default());
+        Self::instantiate(
+            Self::entropy_input_mac(entropy_input),
+            entropy_input,
+            nonce,
+            personalization_string,
+        )
+    }
+
+    /// Initial chaining value `V = 0x01 0x01 0x01 ... 0x01` (see RFC 6979 Section 3.2.b)
+    fn initial_v() -> Array<u8, D::OutputSize> {
         let mut v = Array::default();
+        v.fill(0x01);
+        v
+    }
 
-        for b in &mut v {
-            *b = 0x01;
-        }
+    /// Start computing `HMAC_K(V || 0x00 || entropy_input || ...)` with the
+    /// initial `K` and `V` (see RFC 6979 Sections 3.2.b-d).
+    ///
+    /// This only depends on `entropy_input`.
+    fn entropy_input_mac(entropy_input: &[u8]) -> SimpleHmac<D> {
+        let mut k = SimpleHmac::new(&Default::default());
+        k.update(&Self::initial_v());
+        k.update(&[0x00]);
+        k.update(entropy_input);
+        k
+    }
+
+    /// Finish initializing `HMAC_DRBG` given the output of
+    /// [`HmacDrbg::entropy_input_mac`] for the same `entropy_input`.
+    fn instantiate(
+        mut k: SimpleHmac<D>,
+        entropy_input: &[u8],
+        nonce: &[u8],
+        personalization_string: &[u8],
+    ) -> Self {
+        let mut v = Self::initial_v();
 
         for i in 0..=1 {
-            k.update(&v);
-            k.update(&[i]);
-            k.update(entropy_input);
+            // Steps 3.2.d,f: k = HMAC_k(v || i || entropy_input || nonce || personalization_string)
+            if i == 1 {
+                k.update(&v);
+                k.update(&[i]);
+                k.update(entropy_input);
+            }
+
             k.update(nonce);
             k.update(personalization_string);
             k = SimpleHmac::new_from_slice(&k.finalize().into_bytes()).expect("HMAC error");
@@ -185,11 +279,60 @@ where
     }
 }
 
+/// `HMAC_DRBG` state which only depends on the `entropy_input`, precomputed
+/// so `HMAC_DRBG` can be instantiated with it repeatedly using different
+/// nonces.
+///
+/// In RFC6979 the `entropy_input` is the secret key `x` and the nonce is the
+/// message digest `h`. The HMAC over the prefix `V || 0x00 || int2octets(x)`
+/// in step d of [RFC6979 § 3.2] is keyed with the initial all-zero `K`, so
+/// it's the same for every message signed with a given key. Everything after
+/// that is keyed with values derived from `h`, including step f, and is
+/// computed for each message.
+///
+/// [RFC6979 § 3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-3.2
+#[derive(Clone)]
+pub struct HmacDrbgPrecomputed<'a, D>
+where
+    D: Digest + BlockSizeUser + FixedOutputReset,
+{
+    /// Entropy input, which is needed again in step f
+    entropy_input: &'a [u8],
+
+    /// `HMAC_K` with the initial `K` after absorbing `V || 0x00 || entropy_input`
+    k: SimpleHmac<D>,
+}
+
+impl<'a, D> HmacDrbgPrecomputed<'a, D>
+where
+    D: Digest + BlockSizeUser + FixedOutputReset + Clone,
+{
+    /// Precompute the part of `HMAC_DRBG` initialization which only depends
+    /// on `entropy_input`.
+    pub fn new(entropy_input: &'a [u8]) -> Self {
+        Self {
+            entropy_input,
+            k: HmacDrbg::<D>::entropy_input_mac(entropy_input),
+        }
+    }
+
+    /// Initialize `HMAC_DRBG`, producing the same state as [`HmacDrbg::new`]
+    /// with the same `entropy_input`.
+    pub fn instantiate(&self, nonce: &[u8], personalization_string: &[u8]) -> HmacDrbg<D> {
+        HmacDrbg::instantiate(
+            self.k.clone(),
+            self.entropy_input,
+            nonce,
+            personalization_string,
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        consts::{U21, U66},
-        generate_k, Array,
+        consts::{U21, U30, U32, U36, U66},
+        generate_k, generate_k_precomputed, Array, HmacDrbgPrecomputed,
     };
     use hex_literal::hex;
     use sha2::{Digest, Sha256, Sha512};
@@ -211,6 +354,32 @@ mod tests {
         assert_eq!(k, hex!("023AF4074C90A02B3FE61D286D5C87F425E6BDD81B"));
     }
 
+    /// Examples from RFC6979 Appendix A.2.5, generating `k` for several
+    /// messages from the same precomputed key state.
+    #[test]
+    fn p256_sha256_precomputed() {
+        let q = hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551");
+        let x = hex!("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+        let precomputed = HmacDrbgPrecomputed::<Sha256>::new(&x);
+
+        for (message, expected_k) in [
+            (
+                "sample",
+                hex!("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60"),
+            ),
+            (
+                "test",
+                hex!("D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0"),
+            ),
+        ] {
+            let h = Sha256::digest(message);
+            let aad = b"";
+            let k = generate_k_precomputed::<Sha256, U32>(&precomputed, &q.into(), &h, aad);
+            assert_eq!(k, expected_k);
+            assert_eq!(k, generate_k::<Sha256, U32>(&x.into(), &q.into(), &h, aad));
+        }
+    }
+
     /// Example from RFC6979 Appendix A.2.7.
     #[test]
     fn p521_sha512() {
@@ -236,4 +405,38 @@ mod tests {
 
         assert_eq!(k, expected_k);
     }
+
+    // The following vectors exercise moduli whose bit length isn't a multiple
+    // of 8, and were computed using an independent implementation of
+    // RFC6979 § 3.2 with `h = bits2octets(SHA-256("sample"))`.
+
+    /// 233-bit modulus (B-233 group order): 7 leading zero bits.
+    #[test]
+    fn b233_sha256() {
+        let q = hex!("01000000000000000000000000000013E974E72F8A6922031D2603CFE0D7");
+        let x = hex!("0056ED4FC3CFAAC147CE16261C797AB6BB1C051622EDB7B88A20317031BC");
+        let h = hex!("005E57B7C35536DD83C55BC3AD29E82BA4C01F0A7A68B10F0D9E1EAB33EE");
+
+        let aad = b"";
+        let k = generate_k::<Sha256, U30>(&x.into(), &q.into(), &h.into(), aad);
+        assert_eq!(
+            k,
+            hex!("00873A31E971144D7226024F8AB90D65EA4EBA8E620F705A50F876DA06ED")
+        );
+    }
+
+    /// 281-bit modulus (K-283 group order): 7 leading zero bits.
+    #[test]
+    fn k283_sha256() {
+        let q = hex!("01FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE9AE2ED07577265DFF7F94451E061E163C61");
+        let x = hex!("018CA9FF5A4ABA1322818F834E53F9810C73A4EB0EA95ABB50A6C07BEDA4025E44873973");
+        let h = hex!("00000000AF2BDBE1AA9B6EC1E2ADE1D694F41FC71A831D0268E9891562113D8A62ADD1BF");
+
+        let aad = b"";
+        let k = generate_k::<Sha256, U36>(&x.into(), &q.into(), &h.into(), aad);
+        assert_eq!(
+            k,
+            hex!("01D617E76F494DBA576074284E3BBC85C2858E3C54248E41DE1EBB7ADD0B276AB0A65B85")
+        );
+    }
 }